@@ -0,0 +1,115 @@
+use common::{parse_token, Answer, Generator, Knob, Knobs, ParseError, Rng, Solution};
+use std::collections::HashMap;
+
+pub const EXAMPLES: &[&str] = &[include_str!("../example1.txt")];
+
+fn solve_part1(mut left_list: Vec<u32>, mut right_list: Vec<u32>) -> u32 {
+    left_list.sort();
+    right_list.sort();
+
+    let dist = left_list
+        .iter()
+        .zip(right_list.iter())
+        .fold(0, |acc, (left, right)| acc + left.abs_diff(*right));
+
+    dist
+}
+
+fn solve_part2(left_list: &[u32], right_list: &[u32]) -> u32 {
+    let mut left_map = HashMap::new();
+    let mut right_map = HashMap::new();
+
+    let counter_fn = |list: &[u32], map: &mut HashMap<u32, u32>| {
+        for &number in list.iter() {
+            *map.entry(number).or_insert(0) += 1;
+        }
+    };
+
+    counter_fn(left_list, &mut left_map);
+    counter_fn(right_list, &mut right_map);
+
+    left_map.iter().fold(0, |acc, (left_key, left_val)| {
+        if let Some(right_val) = right_map.get(left_key) {
+            acc + left_key * left_val * right_val
+        } else {
+            acc
+        }
+    })
+}
+
+fn parse(input: &str) -> Result<(Vec<u32>, Vec<u32>), ParseError> {
+    let mut left_list = vec![];
+    let mut right_list = vec![];
+    for l in input.lines().filter(|l| !l.trim().is_empty()) {
+        let mut ids = l.split_whitespace();
+        for list in [&mut left_list, &mut right_list] {
+            let id = ids
+                .next()
+                .ok_or_else(|| ParseError::at(input, &l[l.len()..], "location ID"))?;
+            list.push(parse_token(input, id, "location ID")?);
+        }
+        if let Some(extra) = ids.next() {
+            return Err(ParseError::at(input, extra, "end of line"));
+        }
+    }
+    Ok((left_list, right_list))
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = (Vec<u32>, Vec<u32>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1((left_list, right_list): &Self::Input) -> Answer {
+        solve_part1(left_list.clone(), right_list.clone()).into()
+    }
+
+    fn part2((left_list, right_list): &Self::Input) -> Answer {
+        solve_part2(left_list, right_list).into()
+    }
+}
+
+impl Generator for Day1 {
+    const KNOBS: &'static [Knob] = &[
+        Knob {
+            name: "lines",
+            default: 1000,
+            range: 1..=100_000,
+            help: "pairs of location IDs",
+        },
+        Knob {
+            name: "max_id",
+            default: 99_999,
+            range: 1..=99_999,
+            help: "largest location ID",
+        },
+    ];
+
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        let max_id = knobs.get("max_id") as u64;
+        let left: Vec<u64> = (0..knobs.get("lines"))
+            .map(|_| 1 + rng.below(max_id))
+            .collect();
+
+        // Part 2 is only interesting when some IDs appear in both lists
+        let mut right: Vec<u64> = (0..left.len())
+            .map(|_| {
+                if rng.chance(30) {
+                    *rng.pick(&left)
+                } else {
+                    1 + rng.below(max_id)
+                }
+            })
+            .collect();
+        rng.shuffle(&mut right);
+
+        left.iter()
+            .zip(&right)
+            .map(|(l, r)| format!("{}   {}\n", l, r))
+            .collect()
+    }
+}
@@ -0,0 +1,144 @@
+use common::{Answer, Generator, Grid, Knob, Knobs, ParseError, Pos, Rng, Solution};
+use std::collections::HashSet;
+
+pub const EXAMPLES: &[&str] = &[include_str!("../example1.txt")];
+
+const END_NODE: u32 = 9;
+
+fn find_route_part1(
+    curr_pos: Pos,
+    map: &Grid<u32>,
+    visited: &mut HashSet<Pos>,
+    found_nines: &mut HashSet<Pos>,
+    current_height: u32,
+) {
+    if map[curr_pos] == END_NODE {
+        found_nines.insert(curr_pos);
+        return;
+    }
+
+    for new_pos in map.neighbours(curr_pos) {
+        if !visited.contains(&new_pos) && map[new_pos] == current_height + 1 {
+            visited.insert(new_pos);
+            find_route_part1(new_pos, map, visited, found_nines, current_height + 1);
+            visited.remove(&new_pos);
+        }
+    }
+}
+
+fn find_route_part2(
+    curr_pos: Pos,
+    map: &Grid<u32>,
+    visited: &mut HashSet<Pos>,
+    found_nines: &mut u32,
+    current_height: u32,
+) {
+    if map[curr_pos] == END_NODE {
+        *found_nines += 1;
+        return;
+    }
+
+    for new_pos in map.neighbours(curr_pos) {
+        if !visited.contains(&new_pos) && map[new_pos] == current_height + 1 {
+            visited.insert(new_pos);
+            find_route_part2(new_pos, map, visited, found_nines, current_height + 1);
+            visited.remove(&new_pos);
+        }
+    }
+}
+
+fn solve_part1(input: &Grid<u32>, start_positions: &[Pos]) -> usize {
+    let mut total_score = 0;
+
+    for start in start_positions {
+        let mut visited = HashSet::new();
+        let mut found_nines = HashSet::new();
+
+        visited.insert(*start);
+        find_route_part1(*start, input, &mut visited, &mut found_nines, 0);
+
+        total_score += found_nines.len();
+    }
+
+    total_score
+}
+
+fn solve_part2(input: &Grid<u32>, start_positions: &[Pos]) -> u32 {
+    let mut total_score = 0;
+
+    for start in start_positions {
+        let mut visited = HashSet::new();
+        let mut found_nines: u32 = 0;
+
+        visited.insert(*start);
+        find_route_part2(*start, input, &mut visited, &mut found_nines, 0);
+
+        total_score += found_nines;
+    }
+
+    total_score
+}
+
+fn parse(input: &str) -> Result<(Grid<u32>, Vec<Pos>), ParseError> {
+    let map = Grid::parse(input, "height digit", |c| c.to_digit(10))?;
+    let start_positions: Vec<Pos> = map.find_all(&0).collect();
+
+    Ok((map, start_positions))
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = (Grid<u32>, Vec<Pos>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1((map, start_positions): &Self::Input) -> Answer {
+        solve_part1(map, start_positions).into()
+    }
+
+    fn part2((map, start_positions): &Self::Input) -> Answer {
+        solve_part2(map, start_positions).into()
+    }
+}
+
+impl Generator for Day10 {
+    const KNOBS: &'static [Knob] = &[
+        Knob {
+            name: "size",
+            default: 50,
+            range: 1..=1000,
+            help: "width and height of the map",
+        },
+        Knob {
+            name: "trails",
+            default: 300,
+            range: 0..=100_000,
+            help: "hiking trails climbed from 0 to 9 over the random heights",
+        },
+    ];
+
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        let size = knobs.get("size");
+        let mut map = Grid::new(size, size, 0);
+        for pos in map.positions().collect::<Vec<_>>() {
+            map[pos] = rng.below(10);
+        }
+
+        for _ in 0..knobs.get("trails") {
+            let mut pos = Pos::new(rng.range(0..size), rng.range(0..size));
+            for height in 0..=END_NODE as u64 {
+                map[pos] = height;
+                let neighbours: Vec<Pos> = map.neighbours(pos).collect();
+                if neighbours.is_empty() {
+                    break;
+                }
+                pos = *rng.pick(&neighbours);
+            }
+        }
+
+        map.to_string()
+    }
+}
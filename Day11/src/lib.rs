@@ -0,0 +1,115 @@
+use common::{parse_token, Answer, Generator, Knob, Knobs, ParseError, Rng, Solution};
+use std::collections::{HashMap, VecDeque};
+
+pub const EXAMPLES: &[&str] = &[include_str!("../example1.txt")];
+
+pub fn solve_part1(mut stones: VecDeque<u64>, iterations: u32) -> usize {
+    for _ in 0..iterations {
+        let mut new_stones = VecDeque::new();
+
+        while let Some(stone) = stones.pop_front() {
+            if stone == 0 {
+                new_stones.push_back(1);
+            } else {
+                let stone_str = stone.to_string();
+                let len = stone_str.len();
+
+                if len % 2 == 0 {
+                    let divisor = 10u64.pow((len / 2) as u32);
+                    let left = stone / divisor;
+                    let right = stone % divisor;
+                    new_stones.push_back(left);
+                    new_stones.push_back(right);
+                } else {
+                    new_stones.push_back(stone * 2024);
+                }
+            }
+        }
+
+        stones = new_stones;
+    }
+
+    stones.len()
+}
+
+pub fn count_stones(stone: u64, iterations: u32, cache: &mut HashMap<(u64, u32), u64>) -> u64 {
+    if let Some(&count) = cache.get(&(stone, iterations)) {
+        return count;
+    }
+
+    if iterations == 0 {
+        return 1;
+    }
+
+    let result = if stone == 0 {
+        count_stones(1, iterations - 1, cache)
+    } else {
+        let stone_str = stone.to_string();
+        let len = stone_str.len();
+        if len.is_multiple_of(2) {
+            let divisor = 10u64.pow((len / 2) as u32);
+            count_stones(stone / divisor, iterations - 1, cache)
+                + count_stones(stone % divisor, iterations - 1, cache)
+        } else {
+            count_stones(stone * 2024, iterations - 1, cache)
+        }
+    };
+
+    cache.insert((stone, iterations), result);
+
+    result
+}
+
+fn solve_part2(stones: &VecDeque<u64>, iterations: u32) -> u64 {
+    let mut cache: HashMap<(u64, u32), u64> = HashMap::new();
+    stones
+        .iter()
+        .map(|stone| count_stones(*stone, iterations, &mut cache))
+        .sum()
+}
+
+fn parse(input: &str) -> Result<VecDeque<u64>, ParseError> {
+    input
+        .split_whitespace()
+        .map(|x| parse_token(input, x, "stone number"))
+        .collect()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = VecDeque<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input.clone(), 25).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input, 75).into()
+    }
+}
+
+impl Generator for Day11 {
+    const KNOBS: &'static [Knob] = &[Knob {
+        name: "stones",
+        default: 8,
+        range: 1..=10_000,
+        help: "stones in the initial arrangement",
+    }];
+
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        let stones: Vec<String> = (0..knobs.get("stones"))
+            .map(|_| {
+                // Mix single digits with numbers of up to 7 digits like the real inputs
+                let digits = rng.range(1..8) as u32;
+                rng.below(10u64.pow(digits)).to_string()
+            })
+            .collect();
+
+        format!("{}\n", stones.join(" "))
+    }
+}
@@ -0,0 +1,173 @@
+use common::{Answer, Dir, Generator, Grid, Knob, Knobs, ParseError, Pos, Rng, Solution};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+pub const EXAMPLES: &[&str] = &[include_str!("../example1.txt")];
+
+// Plots of each region, found by flooding from every plot not in a region yet
+fn regions(garden: &Grid<char>) -> Vec<Vec<Pos>> {
+    let mut regions = vec![];
+    let mut visited = HashSet::new();
+
+    for (start, &plant) in garden.iter() {
+        if visited.contains(&start) {
+            continue;
+        }
+
+        let mut region = vec![];
+        let mut queue = VecDeque::new();
+        queue.push_back(start);
+
+        while let Some(current_pos) = queue.pop_front() {
+            if !visited.insert(current_pos) {
+                continue;
+            }
+            region.push(current_pos);
+
+            for neighbor in garden.neighbours(current_pos) {
+                if garden[neighbor] == plant {
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        regions.push(region);
+    }
+
+    regions
+}
+
+// Plots of the region with a fence on the side facing `direction`, for every direction
+fn fences(garden: &Grid<char>, region: &[Pos]) -> HashMap<Dir, HashSet<Pos>> {
+    let mut fence_sides: HashMap<Dir, HashSet<Pos>> = HashMap::new();
+    for &pos in region {
+        for direction in Dir::ORTHOGONAL {
+            let same_plant = garden
+                .step(pos, direction)
+                .is_some_and(|neighbor| garden[neighbor] == garden[pos]);
+            if !same_plant {
+                fence_sides.entry(direction).or_default().insert(pos);
+            }
+        }
+    }
+    fence_sides
+}
+
+fn perimeter(garden: &Grid<char>, region: &[Pos]) -> u32 {
+    let fenced = |pos: Pos, direction: Dir| {
+        garden
+            .step(pos, direction)
+            .is_none_or(|neighbor| garden[neighbor] != garden[pos])
+    };
+    region
+        .iter()
+        .map(|&pos| {
+            Dir::ORTHOGONAL
+                .into_iter()
+                .filter(|&direction| fenced(pos, direction))
+                .count() as u32
+        })
+        .sum()
+}
+
+// Fences facing the same way along a straight line of plots count as a single side
+fn sides(garden: &Grid<char>, region: &[Pos]) -> u32 {
+    let mut sides = 0;
+
+    for fence in fences(garden, region).values() {
+        let mut side_visited = HashSet::new();
+
+        for &pos in fence {
+            if side_visited.contains(&pos) {
+                continue;
+            }
+
+            sides += 1;
+
+            let mut side_queue = VecDeque::new();
+            side_queue.push_back(pos);
+
+            while let Some(side_pos) = side_queue.pop_front() {
+                if side_visited.contains(&side_pos) {
+                    continue;
+                }
+                side_visited.insert(side_pos);
+
+                for neighbor in garden.neighbours(side_pos) {
+                    if fence.contains(&neighbor) {
+                        side_queue.push_back(neighbor);
+                    }
+                }
+            }
+        }
+    }
+
+    sides
+}
+
+fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input, "plant letter", |c| {
+        c.is_ascii_uppercase().then_some(c)
+    })
+}
+
+fn total_price(grid: &Grid<char>, fence_cost: fn(&Grid<char>, &[Pos]) -> u32) -> u32 {
+    regions(grid)
+        .iter()
+        .map(|region| region.len() as u32 * fence_cost(grid, region))
+        .sum()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        total_price(input, perimeter).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        total_price(input, sides).into()
+    }
+}
+
+impl Generator for Day12 {
+    const KNOBS: &'static [Knob] = &[
+        Knob {
+            name: "size",
+            default: 140,
+            range: 1..=1000,
+            help: "width and height of the garden",
+        },
+        Knob {
+            name: "regions",
+            default: 600,
+            range: 1..=10_000,
+            help: "seed points the plots grow from, neighbouring seeds may share a plant",
+        },
+    ];
+
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        let size = knobs.get("size");
+        let seeds: Vec<(Pos, char)> = (0..knobs.get("regions"))
+            .map(|_| {
+                let pos = Pos::new(rng.range(0..size), rng.range(0..size));
+                (pos, char::from(b'A' + rng.below(26) as u8))
+            })
+            .collect();
+
+        // Every tile takes the plant of its nearest seed
+        let mut garden = Grid::new(size, size, 'A');
+        for pos in garden.positions().collect::<Vec<_>>() {
+            if let Some(&(_, plant)) = seeds.iter().min_by_key(|(seed, _)| seed.manhattan(pos)) {
+                garden[pos] = plant;
+            }
+        }
+
+        garden.to_string()
+    }
+}
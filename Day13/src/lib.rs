@@ -0,0 +1,229 @@
+use common::{parse_token, Answer, Generator, Knob, Knobs, ParseError, Rng, Solution};
+use std::cmp;
+use std::collections::HashMap;
+
+pub const EXAMPLES: &[&str] = &[include_str!("../example1.txt")];
+
+const PRIZE_OFFSET: isize = 10000000000000;
+
+pub struct ButtonDesc {
+    x_step: isize,
+    y_step: isize,
+    cost: isize,
+}
+
+pub struct Goal {
+    x: isize,
+    y: isize,
+}
+
+pub fn solve(
+    x: isize,
+    y: isize,
+    button_a: &ButtonDesc,
+    button_b: &ButtonDesc,
+    goal: &Goal,
+    memo: &mut HashMap<(isize, isize), isize>,
+) -> isize {
+    if x == goal.x && y == goal.y {
+        return 0;
+    }
+    if x > goal.x || y > goal.y {
+        return isize::MAX;
+    }
+    if let Some(&cost) = memo.get(&(x, y)) {
+        return cost;
+    }
+
+    let cost_a = solve(
+        x + button_a.x_step,
+        y + button_a.y_step,
+        button_a,
+        button_b,
+        goal,
+        memo,
+    )
+    .saturating_add(button_a.cost);
+
+    let cost_b = solve(
+        x + button_b.x_step,
+        y + button_b.y_step,
+        button_a,
+        button_b,
+        goal,
+        memo,
+    )
+    .saturating_add(button_b.cost);
+
+    let result = cmp::min(cost_a, cost_b);
+    memo.insert((x, y), result);
+    result
+}
+
+pub fn solve_math(button_a: &ButtonDesc, button_b: &ButtonDesc, goal: &Goal) -> Option<isize> {
+    let den = button_a.x_step * button_b.y_step - button_a.y_step * button_b.x_step;
+    if den == 0 {
+        return None;
+    }
+
+    let num_a = goal.x * button_b.y_step - goal.y * button_b.x_step;
+    if num_a % den != 0 {
+        return None;
+    }
+    let a_clicks = num_a / den;
+
+    let num_b = goal.x - button_a.x_step * a_clicks;
+    if num_b % button_b.x_step != 0 {
+        return None;
+    }
+    let b_clicks = num_b / button_b.x_step;
+
+    Some(a_clicks * button_a.cost + b_clicks * button_b.cost)
+}
+
+fn parse_input(input: &str) -> Result<Vec<(ButtonDesc, ButtonDesc, Goal)>, ParseError> {
+    let mut machines = Vec::new();
+    let mut lines = input.lines().filter(|x| !x.trim().is_empty());
+    let mut next_line = |label: &str| {
+        lines.next().ok_or_else(|| {
+            ParseError::at(input, &input[input.len()..], format!("'{}' line", label))
+        })
+    };
+
+    while let Ok(button_a_line) = next_line("Button A:") {
+        let (a_x, a_y) = parse_line(input, button_a_line, "Button A:", '+')?;
+        let (b_x, b_y) = parse_line(input, next_line("Button B:")?, "Button B:", '+')?;
+        let (goal_x, goal_y) = parse_line(input, next_line("Prize:")?, "Prize:", '=')?;
+
+        let button_a = ButtonDesc {
+            x_step: a_x,
+            y_step: a_y,
+            cost: 3,
+        };
+
+        let button_b = ButtonDesc {
+            x_step: b_x,
+            y_step: b_y,
+            cost: 1,
+        };
+
+        let goal = Goal {
+            x: goal_x,
+            y: goal_y,
+        };
+
+        machines.push((button_a, button_b, goal));
+    }
+
+    Ok(machines)
+}
+
+fn parse_axis(
+    input: &str,
+    line: &str,
+    token: Option<&str>,
+    prefix: &str,
+) -> Result<isize, ParseError> {
+    let token = token
+        .map(str::trim)
+        .ok_or_else(|| ParseError::at(input, &line[line.len()..], format!("'{}'", prefix)))?;
+    let value = token
+        .strip_prefix(prefix)
+        .ok_or_else(|| ParseError::at(input, token, format!("'{}'", prefix)))?;
+    parse_token(input, value, "number")
+}
+
+// "Button A: X+94, Y+34" or "Prize: X=8400, Y=5400"
+fn parse_line(
+    input: &str,
+    line: &str,
+    label: &str,
+    sign: char,
+) -> Result<(isize, isize), ParseError> {
+    let rest = line
+        .strip_prefix(label)
+        .ok_or_else(|| ParseError::at(input, line, format!("'{}'", label)))?;
+    let mut axes = rest.split(',');
+
+    let x = parse_axis(input, line, axes.next(), &format!("X{}", sign))?;
+    let y = parse_axis(input, line, axes.next(), &format!("Y{}", sign))?;
+    if let Some(extra) = axes.next() {
+        return Err(ParseError::at(input, extra, "end of line"));
+    }
+
+    Ok((x, y))
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<(ButtonDesc, ButtonDesc, Goal)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    // brute-force approach
+    fn part1(machines: &Self::Input) -> Answer {
+        let mut total_tokens = 0;
+        for (button_a, button_b, goal) in machines.iter() {
+            let mut memo = HashMap::new();
+            let tokens = solve(0, 0, button_a, button_b, goal, &mut memo);
+            if tokens != isize::MAX {
+                total_tokens += tokens;
+            }
+        }
+        total_tokens.into()
+    }
+
+    // linear equation approach
+    fn part2(machines: &Self::Input) -> Answer {
+        let mut total_tokens = 0;
+        for (button_a, button_b, goal) in machines.iter() {
+            let goal = Goal {
+                x: goal.x + PRIZE_OFFSET,
+                y: goal.y + PRIZE_OFFSET,
+            };
+            if let Some(tokens) = solve_math(button_a, button_b, &goal) {
+                total_tokens += tokens;
+            }
+        }
+        total_tokens.into()
+    }
+}
+
+impl Generator for Day13 {
+    const KNOBS: &'static [Knob] = &[Knob {
+        name: "machines",
+        default: 320,
+        range: 1..=100_000,
+        help: "number of claw machines",
+    }];
+
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        let mut machines = vec![];
+
+        for _ in 0..knobs.get("machines") {
+            let button_a = (10 + rng.below(90), 10 + rng.below(90));
+            let button_b = (10 + rng.below(90), 10 + rng.below(90));
+
+            // Most prizes are reachable within 100 presses of each button
+            let prize = if rng.chance(70) {
+                let (a, b) = (rng.below(101), rng.below(101));
+                (
+                    a * button_a.0 + b * button_b.0,
+                    a * button_a.1 + b * button_b.1,
+                )
+            } else {
+                (1000 + rng.below(19_000), 1000 + rng.below(19_000))
+            };
+
+            machines.push(format!(
+                "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                button_a.0, button_a.1, button_b.0, button_b.1, prize.0, prize.1
+            ));
+        }
+
+        machines.join("\n")
+    }
+}
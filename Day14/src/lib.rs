@@ -0,0 +1,217 @@
+use common::{parse_token, Answer, Generator, Knob, Knobs, ParseError, Rng, Solution};
+use itertools::Itertools;
+
+pub const EXAMPLES: &[&str] = &[include_str!("../example1.txt")];
+
+#[derive(Debug, Clone)]
+pub struct Robot {
+    pos_x: usize,
+    pos_y: usize,
+    vel_x: isize,
+    vel_y: isize,
+}
+
+fn simulate(robots: &mut [Robot], width: isize, height: isize) {
+    for robot in robots {
+        let new_x = (robot.pos_x as isize + robot.vel_x).rem_euclid(width);
+        let new_y = (robot.pos_y as isize + robot.vel_y).rem_euclid(height);
+
+        robot.pos_x = new_x as usize;
+        robot.pos_y = new_y as usize;
+    }
+}
+
+fn tree_detected(robots: &[Robot]) -> bool {
+    robots
+        .iter()
+        .map(|robot| (robot.pos_x, robot.pos_y))
+        .all_unique()
+}
+
+fn calculate_safety_factor(
+    mut robots: Vec<Robot>,
+    width: isize,
+    height: isize,
+    steps: usize,
+) -> usize {
+    for _ in 0..steps {
+        simulate(&mut robots, width, height);
+    }
+
+    let mut quadrant_counts = [0; 4];
+    for robot in &robots {
+        let x = robot.pos_x as isize;
+        let y = robot.pos_y as isize;
+        if x == width / 2 || y == height / 2 {
+            continue;
+        }
+
+        let quadrant = match (x < width / 2, y < height / 2) {
+            (true, true) => 0,
+            (false, true) => 1,
+            (true, false) => 2,
+            (false, false) => 3,
+        };
+
+        quadrant_counts[quadrant] += 1;
+    }
+
+    quadrant_counts.iter().product()
+}
+
+// First second from `steps` on with every robot on its own tile. Positions repeat every
+// width * height seconds, so if none of those has it no later one will either.
+fn find_christmas_tree(
+    mut robots: Vec<Robot>,
+    width: isize,
+    height: isize,
+    steps: usize,
+) -> Result<usize, String> {
+    for _ in 0..steps {
+        simulate(&mut robots, width, height);
+    }
+
+    let period = (width * height) as usize;
+    for christmas_tree_event_it in steps..steps + period {
+        if tree_detected(&robots) {
+            return Ok(christmas_tree_event_it);
+        }
+        simulate(&mut robots, width, height);
+    }
+
+    Err(format!(
+        "Robots never all stand on their own tile, their positions repeat every {} seconds",
+        period
+    ))
+}
+
+pub fn render_grid(robots: &[Robot], width: usize, height: usize) -> String {
+    let mut grid = vec![vec![0; width]; height];
+
+    for robot in robots {
+        grid[robot.pos_y][robot.pos_x] += 1;
+    }
+
+    grid.iter()
+        .map(|row| {
+            row.iter()
+                .map(|&count| {
+                    if count == 0 {
+                        '.'
+                    } else {
+                        char::from_digit(count as u32, 10).unwrap_or('9')
+                    }
+                })
+                .collect::<String>()
+        })
+        .join("\n")
+}
+
+const WIDTH: isize = 101;
+const HEIGHT: isize = 103;
+const ITERATIONS: usize = 100;
+
+// The puzzle example places its robots in a smaller 11x7 room
+const EXAMPLE_WIDTH: isize = 11;
+const EXAMPLE_HEIGHT: isize = 7;
+
+fn room_size(robots: &[Robot]) -> (isize, isize) {
+    if robots.iter().all(|robot| {
+        (robot.pos_x as isize) < EXAMPLE_WIDTH && (robot.pos_y as isize) < EXAMPLE_HEIGHT
+    }) {
+        (EXAMPLE_WIDTH, EXAMPLE_HEIGHT)
+    } else {
+        (WIDTH, HEIGHT)
+    }
+}
+
+// "p=0,4" or "v=3,-3"
+fn parse_pair<T: std::str::FromStr>(
+    input: &str,
+    line: &str,
+    part: Option<&str>,
+    prefix: &str,
+) -> Result<(T, T), ParseError> {
+    let part =
+        part.ok_or_else(|| ParseError::at(input, &line[line.len()..], format!("'{}'", prefix)))?;
+    let (x, y) = part
+        .strip_prefix(prefix)
+        .and_then(|pair| pair.split_once(','))
+        .ok_or_else(|| ParseError::at(input, part, format!("'{}x,y'", prefix)))?;
+
+    Ok((
+        parse_token(input, x, "number")?,
+        parse_token(input, y, "number")?,
+    ))
+}
+
+fn parse(input: &str) -> Result<Vec<Robot>, ParseError> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let mut parts = line.split_whitespace();
+
+            let (pos_x, pos_y) = parse_pair(input, line, parts.next(), "p=")?;
+            let (vel_x, vel_y) = parse_pair(input, line, parts.next(), "v=")?;
+
+            Ok(Robot {
+                pos_x,
+                pos_y,
+                vel_x,
+                vel_y,
+            })
+        })
+        .collect()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Robot>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(robots: &Self::Input) -> Answer {
+        let (width, height) = room_size(robots);
+        calculate_safety_factor(robots.clone(), width, height, ITERATIONS).into()
+    }
+
+    fn part2(robots: &Self::Input) -> Answer {
+        let (width, height) = room_size(robots);
+        find_christmas_tree(robots.clone(), width, height, ITERATIONS).into()
+    }
+}
+
+impl Generator for Day14 {
+    const KNOBS: &'static [Knob] = &[Knob {
+        name: "robots",
+        default: 500,
+        range: 1..=(WIDTH * HEIGHT) as usize,
+        help: "robots in the 101x103 room",
+    }];
+
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        // Part 2 looks for the first moment all robots stand apart, so pick such a moment after the
+        // first 100 seconds and rewind every robot from there
+        let picture_at = rng.range(ITERATIONS..(WIDTH * HEIGHT) as usize) as isize;
+
+        let mut tiles: Vec<isize> = (0..WIDTH * HEIGHT).collect();
+        rng.shuffle(&mut tiles);
+
+        tiles[..knobs.get("robots")]
+            .iter()
+            .map(|&tile| {
+                let (x, y) = (tile % WIDTH, tile / WIDTH);
+                let vel_x = rng.below(2 * WIDTH as u64 - 1) as isize - (WIDTH - 1);
+                let vel_y = rng.below(2 * HEIGHT as u64 - 1) as isize - (HEIGHT - 1);
+                let start_x = (x - vel_x * picture_at).rem_euclid(WIDTH);
+                let start_y = (y - vel_y * picture_at).rem_euclid(HEIGHT);
+
+                format!("p={},{} v={},{}\n", start_x, start_y, vel_x, vel_y)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,187 @@
+use common::{Answer, Dir, Generator, Grid, Knob, Knobs, ParseError, Pos, Rng, Solution};
+
+pub const EXAMPLES: &[&str] = &[
+    include_str!("../example1.txt"),
+    include_str!("../example2.txt"),
+];
+
+const OBSTACLE: char = '#';
+const BOX: char = 'O';
+const ROBOT: char = '@';
+const EMPTY: char = '.';
+
+const BOX_PART1: char = '[';
+const BOX_PART2: char = ']';
+
+// Moves the robot one step, pushing every box in the way, unless something hits a wall
+fn move_robot(grid: &mut Grid<char>, direction: Dir, robot_pos: Pos) -> Pos {
+    // Every cell that has to move, ordered by distance from the robot
+    let mut to_move = vec![robot_pos];
+    let mut idx = 0;
+
+    while idx < to_move.len() {
+        let Some(next) = grid.step(to_move[idx], direction) else {
+            return robot_pos;
+        };
+        idx += 1;
+
+        let mut push = |pos: Pos| {
+            if !to_move.contains(&pos) {
+                to_move.push(pos);
+            }
+        };
+        match grid[next] {
+            OBSTACLE => return robot_pos,
+            BOX => push(next),
+            BOX_PART1 => {
+                push(next);
+                push(Pos::new(next.row, next.col + 1));
+            }
+            BOX_PART2 => {
+                push(next);
+                push(Pos::new(next.row, next.col - 1));
+            }
+            _ => {}
+        }
+    }
+
+    // Farthest cells first so nothing is overwritten before it moved
+    for &pos in to_move.iter().rev() {
+        if let Some(next) = grid.step(pos, direction) {
+            grid[next] = grid[pos];
+            grid[pos] = EMPTY;
+        }
+    }
+
+    grid.step(robot_pos, direction).unwrap_or(robot_pos)
+}
+
+fn gps_score(grid: &Grid<char>, box_char: char) -> usize {
+    grid.find_all(&box_char)
+        .map(|pos| 100 * pos.row + pos.col)
+        .sum()
+}
+
+fn solve(grid: &mut Grid<char>, mut robot_pos: Pos, moves: &[Dir], box_char: char) -> usize {
+    for m in moves {
+        robot_pos = move_robot(grid, *m, robot_pos);
+    }
+    gps_score(grid, box_char)
+}
+
+fn convert_grid(grid: &Grid<char>) -> Grid<char> {
+    Grid::from_rows(
+        grid.rows()
+            .map(|row| {
+                row.iter()
+                    .flat_map(|&ch| match ch {
+                        OBSTACLE => [OBSTACLE, OBSTACLE],
+                        BOX => [BOX_PART1, BOX_PART2],
+                        ROBOT => [ROBOT, EMPTY],
+                        _ => [EMPTY, EMPTY],
+                    })
+                    .collect()
+            })
+            .collect(),
+    )
+}
+
+// Warehouse map, the robot's position on it and its moves
+type Warehouse = (Grid<char>, Pos, Vec<Dir>);
+
+fn parse(input: &str) -> Result<Warehouse, ParseError> {
+    // The warehouse map ends at the first blank line, the moves follow
+    let grid_len: usize = input
+        .split_inclusive('\n')
+        .take_while(|line| !line.trim().is_empty())
+        .map(str::len)
+        .sum();
+    let (grid_section, moves_section) = input.split_at(grid_len);
+
+    let grid = Grid::parse(grid_section, "one of #, ., O or @", |c| {
+        "#.O@".contains(c).then_some(c)
+    })?;
+    let robot = grid
+        .find(&ROBOT)
+        .ok_or_else(|| ParseError::at(input, &grid_section[grid_len..], "a robot @"))?;
+
+    let moves: Vec<Dir> = moves_section
+        .char_indices()
+        .filter(|(_, c)| !c.is_whitespace())
+        .map(|(idx, c)| {
+            Dir::from_arrow(c)
+                .ok_or_else(|| ParseError::at(input, &moves_section[idx..], "one of ^, v, < or >"))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((grid, robot, moves))
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Warehouse;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1((grid, robot, moves): &Self::Input) -> Answer {
+        let mut grid = grid.clone();
+        solve(&mut grid, *robot, moves, BOX).into()
+    }
+
+    fn part2((grid, robot, moves): &Self::Input) -> Answer {
+        // Everything but the robot is twice as wide, the robot keeps to the left half of its tile
+        let mut expanded_grid = convert_grid(grid);
+        let robot = Pos::new(robot.row, robot.col * 2);
+        solve(&mut expanded_grid, robot, moves, BOX_PART1).into()
+    }
+}
+
+impl Generator for Day15 {
+    const KNOBS: &'static [Knob] = &[
+        Knob {
+            name: "size",
+            default: 50,
+            range: 3..=1000,
+            help: "width and height of the warehouse, outer walls included",
+        },
+        Knob {
+            name: "boxes",
+            default: 30,
+            range: 0..=90,
+            help: "percentage of floor tiles holding a box",
+        },
+        Knob {
+            name: "moves",
+            default: 20_000,
+            range: 0..=1_000_000,
+            help: "robot moves, written in lines of 1000",
+        },
+    ];
+
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        let size = knobs.get("size");
+        let boxes = knobs.get("boxes") as u64;
+
+        let mut warehouse = Grid::new(size, size, OBSTACLE);
+        for row in 1..size - 1 {
+            for col in 1..size - 1 {
+                warehouse[Pos::new(row, col)] = match rng.below(100) {
+                    roll if roll < boxes => BOX,
+                    roll if roll < boxes + 5 => OBSTACLE,
+                    _ => EMPTY,
+                };
+            }
+        }
+        warehouse[Pos::new(rng.range(1..size - 1), rng.range(1..size - 1))] = ROBOT;
+
+        let moves: Vec<char> = (0..knobs.get("moves"))
+            .map(|_| *rng.pick(&['^', '>', 'v', '<']))
+            .collect();
+        let moves: Vec<String> = moves.chunks(1000).map(String::from_iter).collect();
+
+        format!("{}\n{}\n", warehouse, moves.join("\n"))
+    }
+}
@@ -0,0 +1,129 @@
+use common::search::{self, ShortestPaths};
+use common::{
+    generate_maze, Answer, Dir, Generator, Grid, Knob, Knobs, ParseError, Pos, Rng, Solution,
+};
+use std::collections::HashSet;
+
+pub const EXAMPLES: &[&str] = &[
+    include_str!("../example1.txt"),
+    include_str!("../example2.txt"),
+];
+
+// Maze, start node and end tile
+type Maze = (Grid<char>, Node, Pos);
+
+// Reindeer position and facing
+pub type Node = (Pos, Dir);
+
+// Moving forward costs 1, turning 90 degrees in place costs 1000
+fn moves(maze: &Grid<char>, (pos, dir): Node) -> impl Iterator<Item = (Node, u32)> + '_ {
+    let forward = maze
+        .step(pos, dir)
+        .filter(|&next| maze[next] != '#')
+        .map(|next| ((next, dir), 1));
+
+    forward.into_iter().chain(
+        [dir.turn_right(), dir.turn_left()]
+            .into_iter()
+            .map(move |turned| ((pos, turned), 1000)),
+    )
+}
+
+fn best_paths(maze: &Grid<char>, start: Node) -> ShortestPaths<Node, u32> {
+    search::dijkstra(start, |&node| moves(maze, node))
+}
+
+// Facings the end tile is reached with at the lowest score
+fn best_ends(paths: &ShortestPaths<Node, u32>, end: Pos) -> Result<(u32, Vec<Node>), String> {
+    let score = Dir::ORTHOGONAL
+        .into_iter()
+        .filter_map(|dir| paths.distance(&(end, dir)))
+        .min()
+        .ok_or("No path from S to E")?;
+
+    let ends = Dir::ORTHOGONAL
+        .into_iter()
+        .map(|dir| (end, dir))
+        .filter(|node| paths.distance(node) == Some(score))
+        .collect();
+
+    Ok((score, ends))
+}
+
+fn parse(input: &str) -> Result<Maze, ParseError> {
+    let maze = Grid::parse(input, "one of #, ., S or E", |c| {
+        "#.SE".contains(c).then_some(c)
+    })?;
+
+    let missing = |tile| ParseError::at(input, &input[input.len()..], tile);
+    let start = maze.find(&'S').ok_or_else(|| missing("a start tile S"))?;
+    let end = maze.find(&'E').ok_or_else(|| missing("an end tile E"))?;
+
+    // The reindeer starts facing East
+    Ok((maze, (start, Dir::Right), end))
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Maze;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1((maze, start, end): &Self::Input) -> Answer {
+        let paths = best_paths(maze, *start);
+        best_ends(&paths, *end).map(|(score, _)| score).into()
+    }
+
+    fn part2((maze, start, end): &Self::Input) -> Answer {
+        let paths = best_paths(maze, *start);
+        best_ends(&paths, *end)
+            .map(|(_, ends)| {
+                let tiles: HashSet<Pos> = paths
+                    .nodes_on_paths_to(ends)
+                    .into_iter()
+                    .map(|(pos, _)| pos)
+                    .collect();
+                tiles.len()
+            })
+            .into()
+    }
+}
+
+impl Generator for Day16 {
+    const KNOBS: &'static [Knob] = &[
+        Knob {
+            name: "size",
+            default: 141,
+            range: 5..=1001,
+            help: "width and height of the maze, rounded down to an odd number",
+        },
+        Knob {
+            name: "shortcuts",
+            default: 5,
+            range: 0..=100,
+            help: "percentage of inner walls knocked down to create alternative paths",
+        },
+    ];
+
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        let mut maze = generate_maze(rng, knobs.get("size"));
+        let size = maze.width();
+
+        // Knocked down walls give the reindeer several routes of different scores
+        for row in 1..size - 1 {
+            for col in 1..size - 1 {
+                let pos = Pos::new(row, col);
+                if (row + col) % 2 == 1 && rng.chance(knobs.get("shortcuts") as u64) {
+                    maze[pos] = '.';
+                }
+            }
+        }
+
+        maze[Pos::new(size - 2, 1)] = 'S';
+        maze[Pos::new(1, size - 2)] = 'E';
+        maze.to_string()
+    }
+}
@@ -0,0 +1,127 @@
+use common::{parse_token, ParseError};
+use std::fmt;
+
+use crate::Instruction;
+
+const MNEMONICS: [&str; 8] = ["adv", "bxl", "bst", "jnz", "bxc", "out", "bdv", "cdv"];
+
+// Combo operands 0-3 are literals, 4-6 read a register and 7 is reserved
+fn combo_text(operand: u8) -> String {
+    match operand {
+        4 => "A".to_string(),
+        5 => "B".to_string(),
+        6 => "C".to_string(),
+        7 => "?".to_string(),
+        literal => literal.to_string(),
+    }
+}
+
+// Operand as the effect it has, e.g. "A>>3" for adv 3 or "B%8" for out 5
+fn operand_text(instruction: &Instruction) -> String {
+    let operand = instruction.get_operand();
+    match instruction {
+        Instruction::Adv(_) | Instruction::Bdv(_) | Instruction::Cdv(_) => {
+            format!("A>>{}", combo_text(operand))
+        }
+        Instruction::Bxl(_) => format!("B^{}", operand),
+        Instruction::Bst(_) | Instruction::Out(_) => format!("{}%8", combo_text(operand)),
+        Instruction::Jnz(_) => operand.to_string(),
+        // The operand is read but unused, it's kept so the program assembles back unchanged
+        Instruction::Bxc(_) => format!("B^C ({})", operand),
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {}",
+            MNEMONICS[self.to_number() as usize],
+            operand_text(self)
+        )
+    }
+}
+
+// One instruction per line, prefixed with its index. `jnz` keeps its operand, the address of
+// the target in the opcode list, which is twice its index:
+//    0: bst A%8
+//    1: bxl B^5
+pub fn disassemble(program: &[Instruction]) -> String {
+    program
+        .iter()
+        .enumerate()
+        .map(|(idx, instruction)| format!("{:>4}: {}\n", idx, instruction))
+        .collect()
+}
+
+// Comma-separated opcode list as found after "Program:" in the puzzle input
+pub fn encode(program: &[Instruction]) -> String {
+    program
+        .iter()
+        .flat_map(|instruction| [instruction.to_number(), instruction.get_operand()])
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn parse_literal(source: &str, token: &str) -> Result<u8, ParseError> {
+    match parse_token(source, token, "3-bit number")? {
+        value @ 0..=7 => Ok(value),
+        _ => Err(ParseError::at(source, token, "3-bit number")),
+    }
+}
+
+fn parse_combo(source: &str, token: &str) -> Result<u8, ParseError> {
+    match token {
+        "A" => Ok(4),
+        "B" => Ok(5),
+        "C" => Ok(6),
+        "?" => Ok(7),
+        "0" | "1" | "2" | "3" => parse_token(source, token, "combo operand"),
+        _ => Err(ParseError::at(
+            source,
+            token,
+            "combo operand (0-3, A, B, C or ?)",
+        )),
+    }
+}
+
+// Strips `prefix` and `suffix` off the operand, pointing at the operand when they're missing
+fn unwrap_operand<'a>(
+    source: &str,
+    operand: &'a str,
+    prefix: &str,
+    suffix: &str,
+) -> Result<&'a str, ParseError> {
+    operand
+        .strip_prefix(prefix)
+        .and_then(|rest| rest.strip_suffix(suffix))
+        .ok_or_else(|| ParseError::at(source, operand, format!("'{}..{}'", prefix, suffix)))
+}
+
+// Reads the `disassemble` format back, the address prefixes and blank lines are optional
+pub fn assemble(source: &str) -> Result<Vec<Instruction>, ParseError> {
+    source
+        .lines()
+        .map(|line| line.split_once(':').map_or(line, |(_, rest)| rest).trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let (mnemonic, operand) = line.split_once(' ').unwrap_or((line, &line[line.len()..]));
+            let operand = operand.trim();
+            let opcode = MNEMONICS
+                .iter()
+                .position(|&known| known == mnemonic)
+                .ok_or_else(|| ParseError::at(source, mnemonic, "instruction mnemonic"))?;
+
+            let operand = match opcode {
+                0 | 6 | 7 => parse_combo(source, unwrap_operand(source, operand, "A>>", "")?)?,
+                1 => parse_literal(source, unwrap_operand(source, operand, "B^", "")?)?,
+                2 | 5 => parse_combo(source, unwrap_operand(source, operand, "", "%8")?)?,
+                3 => parse_literal(source, operand)?,
+                _ => parse_literal(source, unwrap_operand(source, operand, "B^C (", ")")?)?,
+            };
+
+            Ok(Instruction::new(opcode as u8, operand))
+        })
+        .collect()
+}
@@ -0,0 +1,180 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::{infinite_loop, Instruction, LoopDetector, Registers, VmError};
+
+// Lower than the budget of a plain run since every step is kept in the trace
+const DEFAULT_STEP_LIMIT: usize = 1_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Breakpoint {
+    // Stops right after the instruction at this index ran
+    Instruction(usize),
+    // Stops right after `out` printed this value
+    Output(u64),
+}
+
+impl Breakpoint {
+    fn is_hit_by(&self, step: &Step) -> bool {
+        match *self {
+            Breakpoint::Instruction(index) => step.index == index,
+            Breakpoint::Output(value) => {
+                matches!(step.instruction, Instruction::Out(_))
+                    && step.output.last() == Some(&value)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Breakpoint::Instruction(index) => write!(f, "instruction {}", index),
+            Breakpoint::Output(value) => write!(f, "out {}", value),
+        }
+    }
+}
+
+// Machine state right after a single instruction ran
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    // Index of the instruction that ran
+    pub index: usize,
+    pub instruction: Instruction,
+    // Index of the instruction that runs next
+    pub instruction_pointer: usize,
+    pub registers: Registers,
+    pub output: Vec<u64>,
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Registers { a, b, c } = self.registers;
+        let output: Vec<String> = self.output.iter().map(|v| v.to_string()).collect();
+        write!(
+            f,
+            "{:>4}: {:<12} ip={:<3} A={} B={} C={} out=[{}]",
+            self.index,
+            self.instruction.to_string(),
+            self.instruction_pointer,
+            a,
+            b,
+            c,
+            output.join(",")
+        )
+    }
+}
+
+// Single-steps a program and records every step for later inspection
+pub struct Debugger<'a> {
+    program: &'a [Instruction],
+    registers: Registers,
+    output: Vec<u64>,
+    instruction_pointer: usize,
+    breakpoints: Vec<Breakpoint>,
+    trace: Vec<Step>,
+    step_limit: usize,
+    // Created on the first step, after the registers may have been overridden
+    detector: Option<LoopDetector>,
+}
+
+impl<'a> Debugger<'a> {
+    pub fn new(program: &'a [Instruction], registers: Registers) -> Self {
+        Debugger {
+            program,
+            registers,
+            output: Vec::new(),
+            instruction_pointer: 0,
+            breakpoints: Vec::new(),
+            trace: Vec::new(),
+            step_limit: DEFAULT_STEP_LIMIT,
+            detector: None,
+        }
+    }
+
+    // Overrides the initial value of A, e.g. to try a candidate from part 2
+    pub fn set_register_a(&mut self, value: u64) {
+        self.registers.a = value;
+    }
+
+    pub fn set_step_limit(&mut self, limit: usize) {
+        self.step_limit = limit;
+    }
+
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) {
+        self.breakpoints.push(breakpoint);
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.instruction_pointer >= self.program.len()
+    }
+
+    pub fn output(&self) -> &[u64] {
+        &self.output
+    }
+
+    pub fn trace(&self) -> &[Step] {
+        &self.trace
+    }
+
+    // Runs the next instruction, None once the program has halted
+    pub fn step(&mut self) -> Result<Option<&Step>, VmError> {
+        if self.is_halted() {
+            return Ok(None);
+        }
+        if self.trace.len() == self.step_limit {
+            return Err(VmError::StepLimitExceeded {
+                limit: self.step_limit,
+            });
+        }
+
+        let index = self.instruction_pointer;
+        let initial_state = (index, self.registers);
+        let detector = self
+            .detector
+            .get_or_insert_with(|| LoopDetector::new(initial_state));
+        self.program[index].evaluate(
+            &mut self.registers,
+            &mut self.output,
+            &mut self.instruction_pointer,
+        )?;
+
+        self.trace.push(Step {
+            index,
+            instruction: self.program[index].clone(),
+            instruction_pointer: self.instruction_pointer,
+            registers: self.registers,
+            output: self.output.clone(),
+        });
+
+        let state = (self.instruction_pointer, self.registers);
+        if let Some(length) = detector.check(state) {
+            return Err(infinite_loop(self.program, state, length));
+        }
+        Ok(self.trace.last())
+    }
+
+    // Steps until a breakpoint is hit, None when the program halted first
+    pub fn resume(&mut self) -> Result<Option<Breakpoint>, VmError> {
+        while self.step()?.is_some() {
+            let step = &self.trace[self.trace.len() - 1];
+            if let Some(&breakpoint) = self.breakpoints.iter().find(|bp| bp.is_hit_by(step)) {
+                return Ok(Some(breakpoint));
+            }
+        }
+
+        Ok(None)
+    }
+
+    // One step per line, in the same format as `Step`'s Display
+    pub fn write_trace(&self, path: &Path) -> io::Result<()> {
+        let trace: String = self
+            .trace
+            .iter()
+            .map(|step| format!("{}\n", step))
+            .collect();
+        fs::write(path, trace)
+    }
+}
@@ -0,0 +1,415 @@
+use common::{parse_token, Answer, Generator, Knob, Knobs, ParseError, Rng, Solution};
+use std::fmt;
+
+mod asm;
+mod debug;
+mod quine;
+
+pub use asm::{assemble, disassemble, encode};
+pub use debug::{Breakpoint, Debugger, Step};
+pub use quine::{find_quine, loop_shift, QuineError};
+
+pub const EXAMPLES: &[&str] = &[
+    include_str!("../example1.txt"),
+    include_str!("../example2.txt"),
+];
+
+// Combo operands that read a register instead of being a literal
+const COMBO_A: u8 = 4;
+const COMBO_B: u8 = 5;
+const COMBO_C: u8 = 6;
+
+// Default step budget, programs still running after this many instructions are reported
+pub const MAX_STEPS: usize = 10_000_000;
+// States of a detected cycle that are kept for the error message
+const SHOWN_CYCLE_STATES: usize = 16;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Registers {
+    pub a: u64,
+    pub b: u64,
+    pub c: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VmError {
+    // Combo operand 7 doesn't denote a value
+    ReservedOperand {
+        index: usize,
+    },
+    // A is divided by 2^shift with a shift of 64 or more
+    ShiftOverflow {
+        index: usize,
+        shift: u64,
+    },
+    StepLimitExceeded {
+        limit: usize,
+    },
+    // The instruction pointer and registers repeated, `cycle` starts with the repeated state
+    InfiniteLoop {
+        length: usize,
+        cycle: Vec<(usize, Registers)>,
+    },
+    // Odd addresses point at an operand instead of an opcode
+    OddJump {
+        index: usize,
+        address: u8,
+    },
+}
+
+impl fmt::Display for VmError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VmError::ReservedOperand { index } => {
+                write!(f, "Instruction {} uses the reserved combo operand 7", index)
+            }
+            VmError::ShiftOverflow { index, shift } => {
+                write!(f, "Instruction {} shifts A right by {} bits", index, shift)
+            }
+            VmError::StepLimitExceeded { limit } => {
+                write!(f, "Program didn't halt within {} steps", limit)
+            }
+            VmError::InfiniteLoop { length, cycle } => {
+                write!(
+                    f,
+                    "Program loops forever, this cycle of {} steps repeats:",
+                    length
+                )?;
+                for (instruction_pointer, Registers { a, b, c }) in cycle {
+                    write!(f, "\n  ip={} A={} B={} C={}", instruction_pointer, a, b, c)?;
+                }
+                if *length > cycle.len() {
+                    write!(f, "\n  ...")?;
+                }
+                Ok(())
+            }
+            VmError::OddJump { index, address } => {
+                write!(f, "Instruction {} jumps to odd address {}", index, address)
+            }
+        }
+    }
+}
+
+impl std::error::Error for VmError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Instruction {
+    Adv(u8),
+    Bxl(u8),
+    Bst(u8),
+    Jnz(u8),
+    Bxc(u8),
+    Out(u8),
+    Bdv(u8),
+    Cdv(u8),
+}
+
+fn evaluate_combo_operand(
+    operand: u8,
+    registers: &Registers,
+    index: usize,
+) -> Result<u64, VmError> {
+    match operand {
+        0..=3 => Ok(operand as u64),
+        COMBO_A => Ok(registers.a),
+        COMBO_B => Ok(registers.b),
+        COMBO_C => Ok(registers.c),
+        _ => Err(VmError::ReservedOperand { index }),
+    }
+}
+
+// A / 2^combo, which is a right shift that runs out of bits at 64
+fn divide_a(operand: u8, registers: &Registers, index: usize) -> Result<u64, VmError> {
+    let shift = evaluate_combo_operand(operand, registers, index)?;
+    u32::try_from(shift)
+        .ok()
+        .and_then(|bits| registers.a.checked_shr(bits))
+        .ok_or(VmError::ShiftOverflow { index, shift })
+}
+
+impl Instruction {
+    pub fn new(opcode: u8, operand: u8) -> Instruction {
+        match opcode {
+            0 => Instruction::Adv(operand),
+            1 => Instruction::Bxl(operand),
+            2 => Instruction::Bst(operand),
+            3 => Instruction::Jnz(operand),
+            4 => Instruction::Bxc(operand),
+            5 => Instruction::Out(operand),
+            6 => Instruction::Bdv(operand),
+            _ => Instruction::Cdv(operand),
+        }
+    }
+
+    // Runs the instruction at `instruction_pointer` and moves the pointer to the next one
+    fn evaluate(
+        &self,
+        registers: &mut Registers,
+        output: &mut Vec<u64>,
+        instruction_pointer: &mut usize,
+    ) -> Result<(), VmError> {
+        let index = *instruction_pointer;
+        *instruction_pointer += 1;
+
+        match self {
+            Instruction::Adv(operand) => registers.a = divide_a(*operand, registers, index)?,
+            Instruction::Bxl(operand) => registers.b ^= *operand as u64,
+            Instruction::Bst(operand) => {
+                registers.b = evaluate_combo_operand(*operand, registers, index)? % 8
+            }
+            Instruction::Jnz(operand) => {
+                if registers.a != 0 {
+                    // The operand is an address in the opcode list, two numbers per instruction
+                    if operand % 2 == 1 {
+                        return Err(VmError::OddJump {
+                            index,
+                            address: *operand,
+                        });
+                    }
+                    *instruction_pointer = *operand as usize / 2;
+                }
+            }
+            Instruction::Bxc(_) => registers.b ^= registers.c,
+            Instruction::Out(operand) => {
+                output.push(evaluate_combo_operand(*operand, registers, index)? % 8)
+            }
+            Instruction::Bdv(operand) => registers.b = divide_a(*operand, registers, index)?,
+            Instruction::Cdv(operand) => registers.c = divide_a(*operand, registers, index)?,
+        }
+
+        Ok(())
+    }
+
+    pub fn to_number(&self) -> u8 {
+        match self {
+            Instruction::Adv(_) => 0,
+            Instruction::Bxl(_) => 1,
+            Instruction::Bst(_) => 2,
+            Instruction::Jnz(_) => 3,
+            Instruction::Bxc(_) => 4,
+            Instruction::Out(_) => 5,
+            Instruction::Bdv(_) => 6,
+            Instruction::Cdv(_) => 7,
+        }
+    }
+
+    pub fn get_operand(&self) -> u8 {
+        match self {
+            Instruction::Adv(operand)
+            | Instruction::Bxl(operand)
+            | Instruction::Bst(operand)
+            | Instruction::Jnz(operand)
+            | Instruction::Bxc(operand)
+            | Instruction::Out(operand)
+            | Instruction::Bdv(operand)
+            | Instruction::Cdv(operand) => *operand,
+        }
+    }
+}
+
+fn parse_registers(input: &str) -> Result<Registers, ParseError> {
+    let mut lines = input.lines();
+
+    let [a, b, c] = ["Register A:", "Register B:", "Register C:"].map(|label| {
+        let line = lines
+            .next()
+            .ok_or_else(|| ParseError::at(input, &input[input.len()..], format!("'{}'", label)))?;
+        let value = line
+            .strip_prefix(label)
+            .ok_or_else(|| ParseError::at(input, line, format!("'{}'", label)))?;
+        parse_token(input, value.trim(), "register value")
+    });
+
+    Ok(Registers {
+        a: a?,
+        b: b?,
+        c: c?,
+    })
+}
+
+fn parse_instructions(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let line = input
+        .lines()
+        .skip(3)
+        .find(|line| !line.trim().is_empty())
+        .ok_or_else(|| ParseError::at(input, &input[input.len()..], "'Program:'"))?;
+    let program = line
+        .strip_prefix("Program:")
+        .ok_or_else(|| ParseError::at(input, line, "'Program:'"))?;
+
+    let values = program
+        .split(',')
+        .map(|x| {
+            let x = x.trim();
+            match parse_token::<u8>(input, x, "3-bit number")? {
+                value @ 0..=7 => Ok((x, value)),
+                _ => Err(ParseError::at(input, x, "3-bit number")),
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    values
+        .chunks(2)
+        .map(|chunk| match *chunk {
+            [(_, opcode), (_, operand)] => Ok(Instruction::new(opcode, operand)),
+            [(opcode, _)] => Err(ParseError::at(input, &opcode[opcode.len()..], "an operand")),
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+// Brent's cycle detection on the instruction pointer and registers. They decide every later
+// step, so a state that comes back means the program never halts.
+struct LoopDetector<S = (usize, Registers)> {
+    saved: S,
+    power: usize,
+    length: usize,
+}
+
+impl<S: Copy + PartialEq> LoopDetector<S> {
+    fn new(state: S) -> Self {
+        LoopDetector {
+            saved: state,
+            power: 1,
+            length: 0,
+        }
+    }
+
+    // Length of the cycle once `state` has been seen before
+    fn check(&mut self, state: S) -> Option<usize> {
+        self.length += 1;
+        if state == self.saved {
+            return Some(self.length);
+        }
+        if self.length == self.power {
+            self.saved = state;
+            self.power *= 2;
+            self.length = 0;
+        }
+        None
+    }
+}
+
+// Replays the first states of a cycle found by LoopDetector
+fn infinite_loop(
+    program: &[Instruction],
+    (mut instruction_pointer, mut registers): (usize, Registers),
+    length: usize,
+) -> VmError {
+    let mut output = Vec::new();
+    let mut cycle = Vec::new();
+    for _ in 0..length.min(SHOWN_CYCLE_STATES) {
+        cycle.push((instruction_pointer, registers));
+        // The states already ran without errors the first time around
+        let _ = program[instruction_pointer].evaluate(
+            &mut registers,
+            &mut output,
+            &mut instruction_pointer,
+        );
+    }
+    VmError::InfiniteLoop { length, cycle }
+}
+
+// Runs the program until it halts, giving up after `max_steps` instructions or when it loops
+pub fn run_program(
+    program: &[Instruction],
+    mut registers: Registers,
+    max_steps: usize,
+) -> Result<Vec<u64>, VmError> {
+    let mut instruction_pointer: usize = 0;
+    let mut output = Vec::new();
+    let mut steps = 0;
+    let mut detector = LoopDetector::new((instruction_pointer, registers));
+
+    while instruction_pointer < program.len() {
+        if steps == max_steps {
+            return Err(VmError::StepLimitExceeded { limit: max_steps });
+        }
+        program[instruction_pointer].evaluate(
+            &mut registers,
+            &mut output,
+            &mut instruction_pointer,
+        )?;
+        steps += 1;
+
+        if let Some(length) = detector.check((instruction_pointer, registers)) {
+            return Err(infinite_loop(
+                program,
+                (instruction_pointer, registers),
+                length,
+            ));
+        }
+    }
+
+    Ok(output)
+}
+
+fn solve_part1(registers: Registers, program: &[Instruction]) -> Result<String, VmError> {
+    Ok(run_program(program, registers, MAX_STEPS)?
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(","))
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = (Registers, Vec<Instruction>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok((parse_registers(input)?, parse_instructions(input)?))
+    }
+
+    fn part1((registers, program): &Self::Input) -> Answer {
+        solve_part1(*registers, program).into()
+    }
+
+    fn part2((_, program): &Self::Input) -> Answer {
+        find_quine(program).into()
+    }
+}
+
+impl Generator for Day17 {
+    const KNOBS: &'static [Knob] = &[Knob {
+        name: "a_bits",
+        default: 48,
+        range: 1..=63,
+        help: "bits of the initial value of register A",
+    }];
+
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        let register_a = rng.below(1 << knobs.get("a_bits")).max(1);
+
+        // Same shape as the puzzle programs: every loop mixes the low bits of A with some higher
+        // ones, prints one value and shifts A right by 3, so the program always halts
+        let mut mixing = vec![
+            Instruction::Bxl(rng.below(8) as u8),
+            Instruction::Bxc(rng.below(8) as u8),
+        ];
+        rng.shuffle(&mut mixing);
+
+        let mut program = vec![
+            Instruction::Bst(COMBO_A),
+            Instruction::Bxl(rng.below(8) as u8),
+            Instruction::Cdv(COMBO_B),
+        ];
+        program.extend(mixing);
+        program.extend([
+            Instruction::Out(COMBO_B),
+            Instruction::Adv(3),
+            Instruction::Jnz(0),
+        ]);
+
+        let program: Vec<String> = program
+            .iter()
+            .map(|instr| format!("{},{}", instr.to_number(), instr.get_operand()))
+            .collect();
+
+        format!(
+            "Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+            register_a,
+            program.join(",")
+        )
+    }
+}
@@ -0,0 +1,336 @@
+use std::fmt;
+
+use crate::{
+    run_program, Instruction, LoopDetector, Registers, COMBO_A, COMBO_B, COMBO_C, MAX_STEPS,
+};
+
+// Candidates for the low bits of A tried before the search for other program shapes gives up
+const SEARCH_NODES: usize = 1 << 20;
+// A candidate that runs longer than this is treated as not reproducing the program
+const CANDIDATE_STEPS: usize = 100_000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QuineError {
+    // The program is a single loop shifting A by `shift` bits, but no A reproduces it
+    NoSolution { shift: u32 },
+    // The program has some other shape and the search ruled out every A
+    Unsatisfiable,
+    // The program has some other shape and the search gave up after trying `nodes` low bits of A
+    Undecided { nodes: usize },
+}
+
+impl fmt::Display for QuineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            QuineError::NoSolution { shift } => write!(
+                f,
+                "No value of A makes the program print itself (single loop shifting A by {})",
+                shift
+            ),
+            QuineError::Unsatisfiable => write!(
+                f,
+                "No value of A makes the program print itself (searched bit by bit, it isn't a \
+                 single loop shifting A by a constant)"
+            ),
+            QuineError::Undecided { nodes } => write!(
+                f,
+                "Gave up looking for a value of A that makes the program print itself after {} \
+                 candidates for its low bits",
+                nodes
+            ),
+        }
+    }
+}
+
+impl std::error::Error for QuineError {}
+
+// Bits the program shifts A by per iteration, if it has the shape the fast search relies on:
+// a single loop closed by `jnz 0` at the end, with one `adv` by a literal and one `out`, where
+// B and C are written before they're read so each iteration only depends on A
+pub fn loop_shift(program: &[Instruction]) -> Option<u32> {
+    let (last, body) = program.split_last()?;
+    if *last != Instruction::Jnz(0) {
+        return None;
+    }
+
+    let mut shift = None;
+    let mut outputs = 0;
+    let mut b_written = false;
+    let mut c_written = false;
+    for instruction in body {
+        let operand = instruction.get_operand();
+        let reads_combo = matches!(
+            instruction,
+            Instruction::Adv(_)
+                | Instruction::Bst(_)
+                | Instruction::Out(_)
+                | Instruction::Bdv(_)
+                | Instruction::Cdv(_)
+        );
+        let reads_b = matches!(instruction, Instruction::Bxl(_) | Instruction::Bxc(_))
+            || (reads_combo && operand == COMBO_B);
+        let reads_c =
+            matches!(instruction, Instruction::Bxc(_)) || (reads_combo && operand == COMBO_C);
+        if (reads_b && !b_written) || (reads_c && !c_written) {
+            return None;
+        }
+
+        match instruction {
+            Instruction::Adv(operand @ 1..=3) if shift.is_none() => shift = Some(*operand as u32),
+            Instruction::Adv(_) | Instruction::Jnz(_) => return None,
+            Instruction::Out(_) => outputs += 1,
+            Instruction::Bst(_) | Instruction::Bdv(_) => b_written = true,
+            Instruction::Cdv(_) => c_written = true,
+            Instruction::Bxl(_) | Instruction::Bxc(_) => {}
+        }
+    }
+
+    shift.filter(|_| outputs == 1)
+}
+
+// Lowest A that makes the program print its own opcode list
+pub fn find_quine(program: &[Instruction]) -> Result<u64, QuineError> {
+    let mut expected_output: Vec<u64> = program
+        .iter()
+        .flat_map(|instr| [instr.to_number() as u64, instr.get_operand() as u64])
+        .collect();
+
+    match loop_shift(program) {
+        Some(shift) => {
+            expected_output.reverse();
+            find_register_value(0, 0, shift, program, &expected_output)
+                .ok_or(QuineError::NoSolution { shift })
+        }
+        None => search_quine(program, &expected_output),
+    }
+}
+
+// Each iteration prints one value and drops `shift` bits of A, so A is built from the last
+// printed value backwards, `shift` bits at a time
+fn find_register_value(
+    register_val: u64,
+    depth: usize,
+    shift: u32,
+    program: &[Instruction],
+    expected_output: &[u64],
+) -> Option<u64> {
+    if depth == expected_output.len() {
+        // Leading zero chunks shorten the output, so the full run has to be checked once
+        let registers = Registers {
+            a: register_val,
+            ..Registers::default()
+        };
+        let mut output = run_program(program, registers, MAX_STEPS).ok()?;
+        output.reverse();
+        return (output == expected_output).then_some(register_val);
+    }
+
+    for i in 0..1 << shift {
+        let a = register_val.checked_mul(1 << shift)? + i;
+        let registers = Registers {
+            a,
+            ..Registers::default()
+        };
+        // A candidate the program fails on can't reproduce it either
+        let output = run_program(program, registers, MAX_STEPS).unwrap_or_default();
+        if !output.is_empty() && output[0] == expected_output[depth] {
+            if let Some(result) = find_register_value(a, depth + 1, shift, program, expected_output)
+            {
+                return Some(result);
+            }
+        }
+    }
+
+    None
+}
+
+// Register value whose lowest `known` bits are known, the others depend on bits of A that
+// aren't chosen yet
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Partial {
+    bits: u64,
+    known: u32,
+}
+
+impl Partial {
+    fn exact(value: u64) -> Self {
+        Partial {
+            bits: value,
+            known: u64::BITS,
+        }
+    }
+
+    fn low(value: u64, known: u32) -> Self {
+        let mask = 1u64.checked_shl(known).map_or(u64::MAX, |bit| bit - 1);
+        Partial {
+            bits: value & mask,
+            known,
+        }
+    }
+
+    fn value(self) -> Option<u64> {
+        (self.known == u64::BITS).then_some(self.bits)
+    }
+
+    fn low3(self) -> Option<u64> {
+        (self.known >= 3).then_some(self.bits % 8)
+    }
+
+    fn shr(self, shift: u64) -> Option<Partial> {
+        let shift = u32::try_from(shift)
+            .ok()
+            .filter(|&shift| shift < u64::BITS)?;
+        let known = match self.known {
+            u64::BITS => u64::BITS,
+            known => known.saturating_sub(shift),
+        };
+        Some(Partial::low(self.bits >> shift, known))
+    }
+
+    fn xor(self, other: Partial) -> Partial {
+        Partial::low(self.bits ^ other.bits, self.known.min(other.known))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    Reproduces,
+    Fails,
+    // The run depends on a bit of A above the known ones
+    NeedsBit,
+}
+
+fn combo(operand: u8, registers: &[Partial; 3]) -> Result<Partial, Outcome> {
+    match operand {
+        0..=3 => Ok(Partial::exact(operand as u64)),
+        COMBO_A..=COMBO_C => Ok(registers[(operand - COMBO_A) as usize]),
+        _ => Err(Outcome::Fails),
+    }
+}
+
+fn divide_a(operand: u8, registers: &[Partial; 3]) -> Result<Partial, Outcome> {
+    let shift = combo(operand, registers)?
+        .value()
+        .ok_or(Outcome::NeedsBit)?;
+    registers[0].shr(shift).ok_or(Outcome::Fails)
+}
+
+// Runs one instruction on partially known registers, giving the value it prints if any
+fn step(
+    instruction: &Instruction,
+    registers: &mut [Partial; 3],
+    instruction_pointer: &mut usize,
+) -> Result<Option<u64>, Outcome> {
+    *instruction_pointer += 1;
+    match instruction {
+        Instruction::Adv(operand) => registers[0] = divide_a(*operand, registers)?,
+        Instruction::Bxl(operand) => {
+            registers[1] = registers[1].xor(Partial::exact(*operand as u64))
+        }
+        Instruction::Bst(operand) => {
+            let value = combo(*operand, registers)?
+                .low3()
+                .ok_or(Outcome::NeedsBit)?;
+            registers[1] = Partial::exact(value);
+        }
+        Instruction::Jnz(operand) => match registers[0] {
+            Partial { bits: 0, known } if known < u64::BITS => return Err(Outcome::NeedsBit),
+            Partial { bits: 0, .. } => {}
+            _ if operand % 2 == 1 => return Err(Outcome::Fails),
+            _ => *instruction_pointer = *operand as usize / 2,
+        },
+        Instruction::Bxc(_) => registers[1] = registers[1].xor(registers[2]),
+        Instruction::Out(operand) => {
+            let value = combo(*operand, registers)?
+                .low3()
+                .ok_or(Outcome::NeedsBit)?;
+            return Ok(Some(value));
+        }
+        Instruction::Bdv(operand) => registers[1] = divide_a(*operand, registers)?,
+        Instruction::Cdv(operand) => registers[2] = divide_a(*operand, registers)?,
+    }
+    Ok(None)
+}
+
+// Runs the program with only the low bits of A known, as far as they decide it. Branches and
+// outputs only ever use known bits, so every A with these low bits takes the same path and a
+// repeated state means none of them halts.
+fn run_candidate(program: &[Instruction], a: Partial, expected_output: &[u64]) -> Outcome {
+    let mut registers = [a, Partial::exact(0), Partial::exact(0)];
+    let mut instruction_pointer = 0;
+    let mut printed = 0;
+    let mut detector = LoopDetector::new((instruction_pointer, registers));
+
+    for _ in 0..CANDIDATE_STEPS {
+        let Some(instruction) = program.get(instruction_pointer) else {
+            return if printed == expected_output.len() {
+                Outcome::Reproduces
+            } else {
+                Outcome::Fails
+            };
+        };
+
+        match step(instruction, &mut registers, &mut instruction_pointer) {
+            Ok(Some(value)) if expected_output.get(printed) != Some(&value) => {
+                return Outcome::Fails
+            }
+            Ok(Some(_)) => printed += 1,
+            Ok(None) => {}
+            Err(outcome) => return outcome,
+        }
+
+        if detector.check((instruction_pointer, registers)).is_some() {
+            return Outcome::Fails;
+        }
+    }
+
+    Outcome::Fails
+}
+
+// Lowest A for programs without the loop shape. A is fixed one bit at a time from the bottom,
+// and a branch is only split further while the run depends on a bit that isn't fixed yet.
+// Candidates at or above the best A found so far can't give a lower one.
+fn search_quine(program: &[Instruction], expected_output: &[u64]) -> Result<u64, QuineError> {
+    if run_candidate(program, Partial::exact(0), expected_output) == Outcome::Reproduces {
+        return Ok(0);
+    }
+
+    let mut best = None;
+    let mut nodes = 0;
+    extend_low_bits(program, expected_output, 0, 0, &mut best, &mut nodes)?;
+    best.ok_or(QuineError::Unsatisfiable)
+}
+
+// `value` with nothing above its `known` low bits has already been run by the caller
+fn extend_low_bits(
+    program: &[Instruction],
+    expected_output: &[u64],
+    value: u64,
+    known: u32,
+    best: &mut Option<u64>,
+    nodes: &mut usize,
+) -> Result<(), QuineError> {
+    *nodes += 1;
+    if *nodes > SEARCH_NODES {
+        return Err(QuineError::Undecided {
+            nodes: SEARCH_NODES,
+        });
+    }
+    if known == u64::BITS
+        || run_candidate(program, Partial::low(value, known), expected_output) != Outcome::NeedsBit
+    {
+        return Ok(());
+    }
+
+    extend_low_bits(program, expected_output, value, known + 1, best, nodes)?;
+
+    let value = value | 1 << known;
+    if best.is_some_and(|best| value >= best) {
+        return Ok(());
+    }
+    if run_candidate(program, Partial::exact(value), expected_output) == Outcome::Reproduces {
+        *best = Some(value);
+        return Ok(());
+    }
+    extend_low_bits(program, expected_output, value, known + 1, best, nodes)
+}
@@ -0,0 +1,173 @@
+use common::search;
+use common::{parse_token, Answer, Generator, Grid, Knob, Knobs, ParseError, Pos, Rng, Solution};
+
+pub const EXAMPLES: &[&str] = &[include_str!("../example1.txt")];
+
+// Marks every corrupted byte, falling bytes are given as X (column), Y (row)
+fn corrupted_space(falling_bytes: &[(usize, usize)], size: usize) -> Grid<bool> {
+    let mut corrupted = Grid::new(size, size, false);
+    for &(x, y) in falling_bytes {
+        corrupted[Pos::new(y, x)] = true;
+    }
+    corrupted
+}
+
+fn open_neighbours(corrupted: &Grid<bool>, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+    corrupted.neighbours(pos).filter(|&next| !corrupted[next])
+}
+
+fn shortest_path(corrupted: &Grid<bool>, start: Pos, end: Pos) -> Option<usize> {
+    let (steps, _) = search::astar(
+        start,
+        |&pos| open_neighbours(corrupted, pos).map(|next| (next, 1)),
+        |&pos| pos.manhattan(end),
+        |&pos| pos == end,
+    )?;
+    Some(steps)
+}
+
+fn parse(input: &str) -> Result<Vec<(usize, usize)>, ParseError> {
+    let coordinate = |token: &str| -> Result<usize, ParseError> {
+        let value = parse_token(input, token, "coordinate")?;
+        if value >= INPUT_SPACE.size {
+            return Err(ParseError::at(input, token, "coordinate below 71"));
+        }
+        Ok(value)
+    };
+
+    let falling_bytes: Vec<(usize, usize)> = input
+        .lines()
+        .map(|line| {
+            let (x, y) = line
+                .split_once(',')
+                .ok_or_else(|| ParseError::at(input, &line[line.len()..], "','"))?;
+            Ok((coordinate(x)?, coordinate(y)?))
+        })
+        .collect::<Result<_, _>>()?;
+
+    if falling_bytes.is_empty() {
+        return Err(ParseError::at(
+            input,
+            &input[input.len()..],
+            "a falling byte",
+        ));
+    }
+    Ok(falling_bytes)
+}
+
+struct MemorySpace {
+    size: usize,
+    fallen_bytes: usize,
+}
+
+const INPUT_SPACE: MemorySpace = MemorySpace {
+    size: 71,
+    fallen_bytes: 1024,
+};
+
+// The puzzle example uses a 7x7 space and only simulates the first 12 bytes
+const EXAMPLE_SPACE: MemorySpace = MemorySpace {
+    size: 7,
+    fallen_bytes: 12,
+};
+
+fn memory_space(falling_bytes: &[(usize, usize)]) -> MemorySpace {
+    if falling_bytes
+        .iter()
+        .all(|&(x, y)| x < EXAMPLE_SPACE.size && y < EXAMPLE_SPACE.size)
+    {
+        EXAMPLE_SPACE
+    } else {
+        INPUT_SPACE
+    }
+}
+
+fn solve_part1(falling_bytes: &[(usize, usize)]) -> Result<usize, String> {
+    let space = memory_space(falling_bytes);
+    let fallen = &falling_bytes[..space.fallen_bytes.min(falling_bytes.len())];
+
+    let start = Pos::new(0, 0);
+    let end = Pos::new(space.size - 1, space.size - 1);
+
+    shortest_path(&corrupted_space(fallen, space.size), start, end).ok_or_else(|| {
+        format!(
+            "No path from 0,0 to the exit once the first {} bytes fell",
+            fallen.len()
+        )
+    })
+}
+
+fn graph_connected(restricted_coords: &[(usize, usize)], space: &MemorySpace) -> bool {
+    let start = Pos::new(0, 0);
+    let end = Pos::new(space.size - 1, space.size - 1);
+
+    let corrupted = corrupted_space(restricted_coords, space.size);
+    search::bfs(start, |&pos| open_neighbours(&corrupted, pos)).contains_key(&end)
+}
+
+// First byte that cuts the exit off, found by binary search on how many bytes have fallen
+fn solve_part2(restricted_coords: &[(usize, usize)]) -> Result<(usize, usize), String> {
+    let space = memory_space(restricted_coords);
+    if graph_connected(restricted_coords, &space) {
+        return Err("The exit is still reachable after every byte has fallen".to_string());
+    }
+
+    let mut low = 0;
+    let mut high = restricted_coords.len() - 1;
+
+    while low < high {
+        let mid = (high + low) / 2;
+        if graph_connected(&restricted_coords[..mid + 1], &space) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    Ok(restricted_coords[low])
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<(usize, usize)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input)
+            .map(|(x, y)| format!("{},{}", x, y))
+            .into()
+    }
+}
+
+impl Generator for Day18 {
+    const KNOBS: &'static [Knob] = &[Knob {
+        name: "bytes",
+        default: 3450,
+        range: 1..=INPUT_SPACE.size * INPUT_SPACE.size - 2,
+        help: "falling bytes in the 71x71 memory space",
+    }];
+
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        let size = INPUT_SPACE.size;
+
+        // Bytes never land on the start or the exit
+        let mut tiles: Vec<(usize, usize)> = (0..size)
+            .flat_map(|x| (0..size).map(move |y| (x, y)))
+            .filter(|&tile| tile != (0, 0) && tile != (size - 1, size - 1))
+            .collect();
+        rng.shuffle(&mut tiles);
+
+        tiles[..knobs.get("bytes")]
+            .iter()
+            .map(|(x, y)| format!("{},{}\n", x, y))
+            .collect()
+    }
+}
@@ -0,0 +1,172 @@
+use common::{Answer, Generator, Knob, Knobs, ParseError, Rng, Solution};
+use std::collections::HashMap;
+
+pub const EXAMPLES: &[&str] = &[include_str!("../example1.txt")];
+
+fn count_combinations<'a>(
+    design: &'a str,
+    towels: &[String],
+    memo: &mut HashMap<&'a str, usize>,
+) -> usize {
+    if design.is_empty() {
+        return 1;
+    }
+
+    if let Some(&result) = memo.get(design) {
+        return result;
+    }
+
+    let mut total_count = 0;
+
+    for towel in towels {
+        if let Some(remaining) = design.strip_prefix(towel) {
+            total_count += count_combinations(remaining, towels, memo);
+        }
+    }
+
+    memo.insert(design, total_count);
+
+    total_count
+}
+
+// Same search as `count_combinations`, but it stops at the first way to make the design
+fn is_possible<'a>(design: &'a str, towels: &[String], memo: &mut HashMap<&'a str, bool>) -> bool {
+    if design.is_empty() {
+        return true;
+    }
+
+    if let Some(&result) = memo.get(design) {
+        return result;
+    }
+
+    let possible = towels.iter().any(|towel| {
+        design
+            .strip_prefix(towel.as_str())
+            .is_some_and(|remaining| is_possible(remaining, towels, memo))
+    });
+
+    memo.insert(design, possible);
+
+    possible
+}
+
+fn solve_part1(towels: &[String], patterns: &[String]) -> usize {
+    patterns
+        .iter()
+        .filter(|pattern| is_possible(pattern, towels, &mut HashMap::new()))
+        .count()
+}
+
+fn solve_part2(towels: &[String], patterns: &[String]) -> usize {
+    patterns
+        .iter()
+        .map(|pattern| count_combinations(pattern, towels, &mut HashMap::new()))
+        .sum()
+}
+
+fn parse_stripes(input: &str, stripes: &str) -> Result<String, ParseError> {
+    match stripes.char_indices().find(|&(_, c)| !"wubrg".contains(c)) {
+        Some((idx, _)) => Err(ParseError::at(
+            input,
+            &stripes[idx..],
+            "stripe colour (w, u, b, r or g)",
+        )),
+        None if stripes.is_empty() => Err(ParseError::at(input, stripes, "stripe colour")),
+        None => Ok(stripes.to_string()),
+    }
+}
+
+fn parse(input: &str) -> Result<(Vec<String>, Vec<String>), ParseError> {
+    let mut lines = input.lines();
+    let mut towels = Vec::new();
+
+    for line in lines.by_ref() {
+        if line.trim().is_empty() {
+            break;
+        }
+        for item in line.split(',') {
+            towels.push(parse_stripes(input, item.trim())?);
+        }
+    }
+
+    let patterns = lines
+        .map(|line| parse_stripes(input, line.trim()))
+        .collect::<Result<_, _>>()?;
+
+    Ok((towels, patterns))
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = (Vec<String>, Vec<String>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1((towels, patterns): &Self::Input) -> Answer {
+        solve_part1(towels, patterns).into()
+    }
+
+    fn part2((towels, patterns): &Self::Input) -> Answer {
+        solve_part2(towels, patterns).into()
+    }
+}
+
+impl Generator for Day19 {
+    const KNOBS: &'static [Knob] = &[
+        Knob {
+            name: "towels",
+            default: 447,
+            range: 1..=10_000,
+            help: "available towel patterns",
+        },
+        Knob {
+            name: "designs",
+            default: 400,
+            range: 1..=100_000,
+            help: "desired designs",
+        },
+    ];
+
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        const COLOURS: [char; 5] = ['w', 'u', 'b', 'r', 'g'];
+        fn stripes(rng: &mut Rng, length: usize) -> String {
+            (0..length).map(|_| *rng.pick(&COLOURS)).collect()
+        }
+
+        // Like the puzzle inputs one colour has no single-stripe towel. Without two-stripe towels
+        // the arrangement counts stay far from overflowing.
+
+        let missing = *rng.pick(&COLOURS);
+        let mut towels: Vec<String> = COLOURS
+            .iter()
+            .filter(|&&colour| colour != missing)
+            .map(|colour| colour.to_string())
+            .collect();
+        while towels.len() < knobs.get("towels") {
+            let length = rng.range(3..9);
+            towels.push(stripes(rng, length));
+        }
+        rng.shuffle(&mut towels);
+
+        let designs: Vec<String> = (0..knobs.get("designs"))
+            .map(|_| {
+                let length = rng.range(20..61);
+                if rng.chance(30) {
+                    return stripes(rng, length);
+                }
+                // Glued from towels, so at least one arrangement exists
+                let mut design = String::new();
+                while design.len() < length {
+                    let towel: &String = rng.pick(&towels);
+                    design.push_str(towel);
+                }
+                design
+            })
+            .collect();
+
+        format!("{}\n\n{}\n", towels.join(", "), designs.join("\n"))
+    }
+}
@@ -0,0 +1,139 @@
+use common::{parse_token, Answer, Generator, Knob, Knobs, ParseError, Rng, Solution};
+
+pub const EXAMPLES: &[&str] = &[include_str!("../example1.txt")];
+
+#[derive(Debug)]
+enum Monotonicity {
+    Increase,
+    Decrease,
+    None,
+}
+
+const MAX_DIFFER: i32 = 3;
+const MIN_DIFFER: i32 = 1;
+
+fn is_within_range(diff: i32) -> bool {
+    (MIN_DIFFER..=MAX_DIFFER).contains(&diff)
+}
+
+fn verify(v: &[i32]) -> Option<()> {
+    let mut monocity = Monotonicity::None;
+
+    for window in v.windows(2) {
+        let (prev, curr) = (window[0], window[1]);
+        let diff = (prev - curr).abs();
+
+        if !is_within_range(diff) {
+            return None;
+        }
+
+        monocity = match monocity {
+            Monotonicity::None => {
+                if curr > prev {
+                    Monotonicity::Increase
+                } else {
+                    Monotonicity::Decrease
+                }
+            }
+            Monotonicity::Increase if curr < prev => return None,
+            Monotonicity::Decrease if curr > prev => return None,
+            _ => monocity,
+        };
+    }
+
+    Some(())
+}
+
+fn solve(input: &[Vec<i32>], solve_part2: bool) -> u32 {
+    let mut valid_levels = 0;
+
+    for v in input {
+        match verify(v.as_slice()) {
+            Some(()) => {
+                valid_levels += 1;
+            }
+            None if solve_part2
+                && (0..v.len()).any(|skip_idx| {
+                    let modified = v
+                        .iter()
+                        .enumerate()
+                        .filter_map(|(idx, &val)| if idx != skip_idx { Some(val) } else { None })
+                        .collect::<Vec<_>>();
+                    matches!(verify(&modified), Some(()))
+                }) =>
+            {
+                valid_levels += 1;
+            }
+            _ => {}
+        }
+    }
+
+    valid_levels
+}
+
+fn parse(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            line.split_whitespace()
+                .map(|num| parse_token(input, num, "level"))
+                .collect()
+        })
+        .collect()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve(input, false).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve(input, true).into()
+    }
+}
+
+impl Generator for Day2 {
+    const KNOBS: &'static [Knob] = &[Knob {
+        name: "reports",
+        default: 1000,
+        range: 1..=100_000,
+        help: "number of reports",
+    }];
+
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        let mut output = String::new();
+
+        for _ in 0..knobs.get("reports") {
+            let length = rng.range(5..9);
+            let increasing = rng.chance(50);
+            let mut level = rng.range(25..76) as i32;
+            let mut levels = vec![level];
+
+            for _ in 1..length {
+                let step = rng.range(1..4) as i32;
+                level += if increasing { step } else { -step };
+                levels.push(level);
+            }
+
+            // Break some reports with a single bad level, a few of them beyond repair
+            for _ in 0..[0, 0, 1, 2][rng.range(0..4)] {
+                let idx = rng.range(0..length);
+                levels[idx] += rng.range(0..7) as i32 - 3;
+            }
+
+            let line: Vec<String> = levels.iter().map(i32::to_string).collect();
+            output.push_str(&line.join(" "));
+            output.push('\n');
+        }
+
+        output
+    }
+}
@@ -0,0 +1,131 @@
+use common::search;
+use common::{generate_maze, Answer, Generator, Grid, Knob, Knobs, ParseError, Pos, Rng, Solution};
+use std::collections::HashSet;
+
+pub const EXAMPLES: &[&str] = &[include_str!("../example1.txt")];
+
+const WALL: char = '#';
+const START: char = 'S';
+
+// Steps from the start for every track tile, -1 for walls
+type Distances = Grid<isize>;
+
+fn calc_distances(grid: &Grid<char>, start: Pos) -> Distances {
+    let steps = search::bfs(start, |&pos| {
+        grid.neighbours(pos).filter(|&next| grid[next] != WALL)
+    });
+
+    let mut distances: Distances = Grid::new(grid.width(), grid.height(), -1);
+    for (pos, step) in steps {
+        distances[pos] = step as isize;
+    }
+
+    distances
+}
+
+// Track tiles reachable by cheating through walls for exactly `range` picoseconds
+fn cheat_targets(grid: &Grid<char>, pos: Pos, range: usize) -> HashSet<Pos> {
+    (0..=range as isize)
+        .flat_map(|step_r| {
+            let step_c = range as isize - step_r;
+            [
+                (-step_r, -step_c),
+                (step_r, step_c),
+                (step_r, -step_c),
+                (-step_r, step_c),
+            ]
+        })
+        .filter_map(|offset| pos.offset(offset))
+        .filter(|&target| grid.get(target).is_some_and(|&tile| tile != WALL))
+        .collect()
+}
+
+fn count_cheats(distances: &Distances, grid: &Grid<char>, max_range: usize) -> usize {
+    let mut count = 0;
+
+    for (pos, &tile) in grid.iter() {
+        if tile == WALL {
+            continue;
+        }
+
+        for range in 2..=max_range {
+            for target in cheat_targets(grid, pos, range) {
+                if distances[target] - distances[pos] >= range as isize + 100 {
+                    count += 1;
+                }
+            }
+        }
+    }
+
+    count
+}
+
+fn solve_part1(distances: &Distances, grid: &Grid<char>) -> usize {
+    count_cheats(distances, grid, 2)
+}
+
+fn solve_part2(distances: &Distances, grid: &Grid<char>) -> usize {
+    count_cheats(distances, grid, 20)
+}
+
+fn parse(input: &str) -> Result<(Grid<char>, Distances), ParseError> {
+    let grid = Grid::parse(input, "one of #, ., S or E", |c| {
+        "#.SE".contains(c).then_some(c)
+    })?;
+
+    let start = grid
+        .find(&START)
+        .ok_or_else(|| ParseError::at(input, &input[input.len()..], "a start tile S"))?;
+    let distances = calc_distances(&grid, start);
+
+    Ok((grid, distances))
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = (Grid<char>, Distances);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1((grid, distances): &Self::Input) -> Answer {
+        solve_part1(distances, grid).into()
+    }
+
+    fn part2((grid, distances): &Self::Input) -> Answer {
+        solve_part2(distances, grid).into()
+    }
+}
+
+impl Generator for Day20 {
+    const KNOBS: &'static [Knob] = &[Knob {
+        name: "size",
+        default: 141,
+        range: 5..=1001,
+        help: "width and height of the racetrack, rounded down to an odd number",
+    }];
+
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        let maze = generate_maze(rng, knobs.get("size"));
+        let size = maze.width();
+        let start = Pos::new(size - 2, 1);
+        let end = Pos::new(1, size - 2);
+
+        // The race is a single track, so only the maze's path from start to end stays open
+        let paths = search::dijkstra(start, |&pos| {
+            maze.neighbours(pos)
+                .filter(|&next| maze[next] != WALL)
+                .map(|next| (next, 1))
+        });
+        let mut track = Grid::new(size, size, WALL);
+        for pos in paths.path_to(&end).unwrap_or_default() {
+            track[pos] = '.';
+        }
+
+        track[start] = START;
+        track[end] = 'E';
+        track.to_string()
+    }
+}
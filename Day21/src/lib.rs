@@ -0,0 +1,219 @@
+use common::search::{self, ShortestPaths};
+use common::{Answer, Dir, Generator, Grid, Knob, Knobs, ParseError, Pos, Rng, Solution};
+use itertools::Itertools;
+use std::collections::HashMap;
+
+pub const EXAMPLES: &[&str] = &[include_str!("../example1.txt")];
+
+const GAP: char = 'X';
+
+// Arrow presses for every shortest route between two keys, each ending with 'A'
+fn key_paths(paths: &ShortestPaths<Pos, usize>, end: Pos) -> Vec<Vec<char>> {
+    paths
+        .paths_to(&end)
+        .iter()
+        .map(|path| {
+            let mut moves: Vec<char> = path
+                .windows(2)
+                .filter_map(|step| {
+                    Dir::ORTHOGONAL
+                        .into_iter()
+                        .find(|&dir| step[0].step(dir) == Some(step[1]))
+                        .and_then(Dir::to_arrow)
+                })
+                .collect();
+            moves.push('A');
+            moves
+        })
+        .collect()
+}
+
+fn calculate_combinations(keypad: &Grid<char>) -> Combinations {
+    let positions: HashMap<char, Pos> = keypad
+        .iter()
+        .filter(|(_, &key)| key != GAP)
+        .map(|(pos, &key)| (key, pos))
+        .collect();
+
+    let mut seqs: Combinations = HashMap::new();
+    for (&start, &start_pos) in &positions {
+        let paths = search::dijkstra(start_pos, |&pos| {
+            keypad
+                .neighbours(pos)
+                .filter(|&next| keypad[next] != GAP)
+                .map(|next| (next, 1))
+        });
+        for (&dest, &dest_pos) in &positions {
+            seqs.insert((start, dest), key_paths(&paths, dest_pos));
+        }
+    }
+
+    seqs
+}
+
+fn convert_code_into_pairs(code: &[char]) -> Vec<(char, char)> {
+    let mut moves = Vec::new();
+    if let Some(&first) = code.first() {
+        moves.push(('A', first));
+    }
+    moves.extend(code.windows(2).map(|window| (window[0], window[1])));
+    moves
+}
+
+fn get_solutions(code: &[char], seqs: &HashMap<(char, char), Vec<Vec<char>>>) -> Vec<Vec<char>> {
+    let moves = convert_code_into_pairs(code);
+
+    let options: Vec<Vec<Vec<char>>> = moves
+        .iter()
+        .filter_map(|(start, end)| seqs.get(&(*start, *end)).cloned())
+        .collect();
+
+    options
+        .into_iter()
+        .multi_cartesian_product()
+        .map(|x| x.into_iter().flatten().collect::<Vec<char>>())
+        .collect()
+}
+
+fn compute<'a>(
+    code: &'a Vec<char>,
+    depth: usize,
+    dir_seqs: &'a HashMap<(char, char), Vec<Vec<char>>>,
+    dir_lengths: &HashMap<(char, char), usize>,
+    cache: &mut HashMap<(&'a Vec<char>, usize), usize>,
+) -> usize {
+    if depth == 1 {
+        return convert_code_into_pairs(code)
+            .into_iter()
+            .map(|x| dir_lengths.get(&x).unwrap_or(&0))
+            .sum();
+    }
+
+    if let Some(&len) = cache.get(&(code, depth)) {
+        return len;
+    }
+
+    let mut len = 0;
+    for (start, end) in convert_code_into_pairs(code) {
+        len += dir_seqs
+            .get(&(start, end))
+            .unwrap()
+            .iter()
+            .map(|seq| compute(seq, depth - 1, dir_seqs, dir_lengths, cache))
+            .min()
+            .unwrap_or(0);
+    }
+
+    cache.insert((code, depth), len);
+    len
+}
+
+fn solve(
+    codes: &[String],
+    num_robots: usize,
+    dir_combinations: &HashMap<(char, char), Vec<Vec<char>>>,
+    num_combinations: &HashMap<(char, char), Vec<Vec<char>>>,
+) -> usize {
+    let dir_lengths: HashMap<(char, char), usize> = dir_combinations
+        .iter()
+        .map(|(movement, paths)| (*movement, paths[0].len()))
+        .collect();
+
+    let mut total_complexity = 0;
+
+    for line in codes {
+        let mut cache: HashMap<(&Vec<char>, usize), usize> = HashMap::new();
+        let line_chr: Vec<char> = line.chars().collect();
+        let min_solution = get_solutions(&line_chr, num_combinations)
+            .iter()
+            .map(|sol| compute(sol, num_robots, dir_combinations, &dir_lengths, &mut cache))
+            .min()
+            .unwrap();
+        total_complexity += min_solution * line[..line.len() - 1].parse::<usize>().unwrap();
+    }
+
+    total_complexity
+}
+
+type Combinations = HashMap<(char, char), Vec<Vec<char>>>;
+
+fn keypad_combinations() -> (Combinations, Combinations) {
+    let num_keypad = Grid::from_rows(vec![
+        vec!['7', '8', '9'],
+        vec!['4', '5', '6'],
+        vec!['1', '2', '3'],
+        vec![GAP, '0', 'A'],
+    ]);
+
+    let dir_keypad = Grid::from_rows(vec![vec![GAP, '^', 'A'], vec!['<', 'v', '>']]);
+
+    (
+        calculate_combinations(&dir_keypad),
+        calculate_combinations(&num_keypad),
+    )
+}
+
+const MAX_CODE_DIGITS: usize = 3;
+
+// Door codes are digits followed by a final 'A', e.g. "029A"
+fn parse_code(input: &str, line: &str) -> Result<String, ParseError> {
+    let digits = line.strip_suffix('A').unwrap_or(line);
+    if let Some((idx, _)) = digits.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+        return Err(ParseError::at(input, &digits[idx..], "digit or final 'A'"));
+    }
+    if digits.is_empty() {
+        return Err(ParseError::at(input, digits, "digit"));
+    }
+    // Door codes have three digits, the complexity of much longer ones overflows
+    if digits.len() > MAX_CODE_DIGITS {
+        return Err(ParseError::at(
+            input,
+            &digits[MAX_CODE_DIGITS..],
+            "final 'A'",
+        ));
+    }
+    if digits.len() == line.len() {
+        return Err(ParseError::at(input, &line[line.len()..], "final 'A'"));
+    }
+
+    Ok(line.to_string())
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| parse_code(input, line.trim()))
+            .collect()
+    }
+
+    fn part1(codes: &Self::Input) -> Answer {
+        let (dir_combinations, num_combinations) = keypad_combinations();
+        solve(codes, 2, &dir_combinations, &num_combinations).into()
+    }
+
+    fn part2(codes: &Self::Input) -> Answer {
+        let (dir_combinations, num_combinations) = keypad_combinations();
+        solve(codes, 25, &dir_combinations, &num_combinations).into()
+    }
+}
+
+impl Generator for Day21 {
+    const KNOBS: &'static [Knob] = &[Knob {
+        name: "codes",
+        default: 5,
+        range: 1..=10_000,
+        help: "door codes to type",
+    }];
+
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        (0..knobs.get("codes"))
+            .map(|_| format!("{:03}A\n", rng.below(1000)))
+            .collect()
+    }
+}
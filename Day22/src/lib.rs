@@ -0,0 +1,103 @@
+use common::{parse_token, Answer, Generator, Knob, Knobs, ParseError, Rng, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub const EXAMPLES: &[&str] = &[
+    include_str!("../example1.txt"),
+    include_str!("../example2.txt"),
+];
+
+fn mix(secret_num: usize, calc_value: usize) -> usize {
+    calc_value ^ secret_num
+}
+
+fn prune(secret_num: usize) -> usize {
+    secret_num % 16_777_216
+}
+
+fn produce_secret_num(secret_num: usize) -> usize {
+    let mut step = prune(mix(secret_num, secret_num * 64));
+    step = prune(mix(step, step / 32));
+    prune(mix(step, step * 2048))
+}
+
+fn final_secret(secret_num: usize, iterations: usize) -> usize {
+    (0..iterations).fold(secret_num, |secret_num, _| produce_secret_num(secret_num))
+}
+
+fn solve_part1(initial_secrets: &[usize], iterations: usize) -> usize {
+    initial_secrets
+        .iter()
+        .map(|&secret_num| final_secret(secret_num, iterations))
+        .sum()
+}
+
+fn solve_part2(initial_secrets: &[usize], iterations: usize) -> usize {
+    let mut seq_profit: HashMap<(isize, isize, isize, isize), usize> = HashMap::new();
+
+    for &secret_num in initial_secrets {
+        let mut secret_num = secret_num;
+        let mut visited = HashSet::new();
+        let mut prices = Vec::with_capacity(iterations + 1);
+
+        prices.push(secret_num % 10);
+        (0..iterations).for_each(|_| {
+            secret_num = produce_secret_num(secret_num);
+            prices.push(secret_num % 10);
+        });
+
+        for window in prices.windows(5) {
+            let [a, b, c, d, e] = [window[0], window[1], window[2], window[3], window[4]];
+            let seq = (
+                b as isize - a as isize,
+                c as isize - b as isize,
+                d as isize - c as isize,
+                e as isize - d as isize,
+            );
+            if visited.insert(seq) {
+                *seq_profit.entry(seq).or_insert(0) += e;
+            }
+        }
+    }
+
+    *seq_profit.values().max().unwrap_or(&0)
+}
+
+const ITERATIONS: usize = 2000;
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| parse_token(input, line.trim(), "initial secret number"))
+            .collect()
+    }
+
+    fn part1(initial_secrets: &Self::Input) -> Answer {
+        solve_part1(initial_secrets, ITERATIONS).into()
+    }
+
+    fn part2(initial_secrets: &Self::Input) -> Answer {
+        solve_part2(initial_secrets, ITERATIONS).into()
+    }
+}
+
+impl Generator for Day22 {
+    const KNOBS: &'static [Knob] = &[Knob {
+        name: "buyers",
+        default: 2000,
+        range: 1..=100_000,
+        help: "buyers and their initial secret numbers",
+    }];
+
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        // Secrets are pruned to 24 bits, so initial ones stay below 2^24 as well
+        (0..knobs.get("buyers"))
+            .map(|_| format!("{}\n", 1 + rng.below((1 << 24) - 1)))
+            .collect()
+    }
+}
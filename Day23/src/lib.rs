@@ -0,0 +1,183 @@
+use common::{Answer, Generator, Knob, Knobs, ParseError, Rng, Solution};
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+pub const EXAMPLES: &[&str] = &[include_str!("../example1.txt")];
+
+type Graph = HashMap<String, HashSet<String>>;
+
+fn build_graph(input: &str) -> Result<Graph, ParseError> {
+    let mut graph: Graph = HashMap::new();
+
+    for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        let (left, right) = line
+            .split_once('-')
+            .ok_or_else(|| ParseError::at(input, &line[line.len()..], "'-'"))?;
+        for name in [left, right] {
+            if name.is_empty() || !name.chars().all(|c| c.is_ascii_lowercase()) {
+                return Err(ParseError::at(input, name, "computer name"));
+            }
+        }
+        graph
+            .entry(left.to_string())
+            .or_default()
+            .insert(right.to_string());
+        graph
+            .entry(right.to_string())
+            .or_default()
+            .insert(left.to_string());
+    }
+
+    Ok(graph)
+}
+
+fn solve_part1(graph: &Graph) -> usize {
+    let mut conn_computers: HashSet<(&str, &str, &str)> = HashSet::new();
+
+    for (node1, neighbors1) in graph {
+        for node2 in neighbors1 {
+            if let Some(neighbors2) = graph.get(node2) {
+                for node3 in neighbors2 {
+                    if node1 != node3 && graph.get(node3).unwrap_or(&HashSet::new()).contains(node1)
+                    {
+                        let mut group = [node1.as_str(), node2.as_str(), node3.as_str()];
+                        group.sort();
+                        if group.iter().any(|&node| node.starts_with('t')) {
+                            conn_computers.insert((group[0], group[1], group[2]));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    conn_computers.len()
+}
+
+fn find_connections<'a>(
+    node: &str,
+    curr_group: &mut HashSet<&'a str>,
+    groups: &mut HashSet<Vec<&'a str>>,
+    graph: &'a Graph,
+) {
+    let mut sorted: Vec<&str> = curr_group.iter().cloned().collect();
+    sorted.sort();
+    if groups.contains(&sorted) {
+        return;
+    }
+    groups.insert(sorted);
+    if let Some(neighbors) = graph.get(node) {
+        for neighbor in neighbors {
+            if neighbor == node {
+                continue;
+            }
+            if !curr_group
+                .iter()
+                .all(|x| graph.get(neighbor).unwrap_or(&HashSet::new()).contains(*x))
+            {
+                continue;
+            }
+            if !curr_group.contains(neighbor.as_str()) {
+                curr_group.insert(neighbor);
+                find_connections(neighbor, curr_group, groups, graph);
+                curr_group.remove(neighbor.as_str());
+            }
+        }
+    }
+}
+
+fn solve_part2(graph: &Graph) -> Option<String> {
+    let mut groups: HashSet<Vec<&str>> = HashSet::new();
+
+    for node in graph.keys() {
+        let mut curr_group: HashSet<&str> = HashSet::new();
+        curr_group.insert(node);
+        find_connections(node, &mut curr_group, &mut groups, graph);
+    }
+
+    groups
+        .iter()
+        .max_by_key(|group| group.len())
+        .map(|largest_group| largest_group.join(","))
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Graph;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        build_graph(input)
+    }
+
+    fn part1(graph: &Self::Input) -> Answer {
+        solve_part1(graph).into()
+    }
+
+    fn part2(graph: &Self::Input) -> Answer {
+        solve_part2(graph).map_or(Answer::None, Answer::from)
+    }
+}
+
+impl Generator for Day23 {
+    const KNOBS: &'static [Knob] = &[
+        Knob {
+            name: "computers",
+            default: 520,
+            range: 2..=676,
+            help: "computers on the network, named by two lowercase letters",
+        },
+        Knob {
+            name: "degree",
+            default: 13,
+            range: 1..=100,
+            help: "connections per computer, on average",
+        },
+        Knob {
+            name: "party",
+            default: 13,
+            range: 2..=676,
+            help: "computers in the planted LAN party, all connected to each other",
+        },
+    ];
+
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        let mut names: Vec<String> = ('a'..='z')
+            .flat_map(|first| ('a'..='z').map(move |second| format!("{}{}", first, second)))
+            .collect();
+        rng.shuffle(&mut names);
+        names.truncate(knobs.get("computers"));
+
+        // Ordered so the same seed always produces the same lines
+        let mut connections: BTreeSet<(usize, usize)> = BTreeSet::new();
+        let party = knobs.get("party").min(names.len());
+        for i in 0..party {
+            for j in i + 1..party {
+                connections.insert((i, j));
+            }
+        }
+
+        // Random extra connections until the average degree is reached, or the graph is complete
+        let wanted =
+            (names.len() * knobs.get("degree") / 2).min(names.len() * (names.len() - 1) / 2);
+        while connections.len() < wanted {
+            let (a, b) = (rng.range(0..names.len()), rng.range(0..names.len()));
+            if a != b {
+                connections.insert((a.min(b), a.max(b)));
+            }
+        }
+
+        let mut lines: Vec<String> = connections
+            .iter()
+            .map(|&(a, b)| {
+                if rng.chance(50) {
+                    format!("{}-{}", names[a], names[b])
+                } else {
+                    format!("{}-{}", names[b], names[a])
+                }
+            })
+            .collect();
+        rng.shuffle(&mut lines);
+
+        format!("{}\n", lines.join("\n"))
+    }
+}
@@ -0,0 +1,162 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::swaps::flagged_wires;
+use crate::{Circuit, Operation};
+
+fn fill_colour(operand: &str) -> &'static str {
+    match operand {
+        "AND" => "#9ecae1",
+        "OR" => "#a1d99b",
+        "XOR" => "#fdae6b",
+        "NAND" => "#c6dbef",
+        "NOR" => "#c7e9c0",
+        "XNOR" => "#fdd0a2",
+        _ => "#d9d9d9",
+    }
+}
+
+// Gates sorted by output wire so the same circuit always gives the same graph
+fn sorted_gates(circuit: &Circuit) -> Vec<&Operation> {
+    let mut gates: Vec<&Operation> = circuit.gates.iter().collect();
+    gates.sort_by(|a, b| a.output.cmp(&b.output));
+    gates
+}
+
+fn sorted_inputs(circuit: &Circuit) -> Vec<&str> {
+    let mut inputs: Vec<&str> = circuit
+        .initial_wires
+        .iter()
+        .map(|(wire, _)| wire.as_str())
+        .collect();
+    inputs.sort();
+    inputs
+}
+
+// Graphviz graph with one node per gate, named after its output wire. Inputs are on the left,
+// z outputs on the right and the gates `flagged_wires` blames for the adder being wrong are
+// outlined in red, along with the wires leaving them.
+pub fn to_dot(circuit: &Circuit) -> String {
+    let flagged: HashMap<String, String> = flagged_wires(circuit).into_iter().collect();
+    let mut lines = vec![
+        "digraph circuit {".to_string(),
+        "    rankdir=LR;".to_string(),
+        "    node [style=filled, fillcolor=white];".to_string(),
+    ];
+
+    lines.push("    {".to_string());
+    lines.push("        rank=source;".to_string());
+    for wire in sorted_inputs(circuit) {
+        lines.push(format!("        \"{}\" [shape=box];", wire));
+    }
+    lines.push("    }".to_string());
+
+    let mut outputs = vec![];
+    for op in sorted_gates(circuit) {
+        let mut node = format!(
+            "\"{}\" [label=\"{}\\n{}\", fillcolor=\"{}\"",
+            op.output,
+            op.output,
+            op.operand,
+            fill_colour(&op.operand)
+        );
+        if let Some(reason) = flagged.get(&op.output) {
+            node.push_str(&format!(", color=red, penwidth=3, tooltip=\"{}\"", reason));
+        }
+        node.push_str("];");
+
+        if op.output.starts_with('z') {
+            outputs.push(node);
+        } else {
+            lines.push(format!("    {}", node));
+        }
+    }
+    lines.push("    {".to_string());
+    lines.push("        rank=sink;".to_string());
+    lines.extend(outputs.into_iter().map(|node| format!("        {}", node)));
+    lines.push("    }".to_string());
+
+    for op in sorted_gates(circuit) {
+        for wire in op.inputs() {
+            let style = if flagged.contains_key(wire) {
+                " [color=red, penwidth=2]"
+            } else {
+                ""
+            };
+            lines.push(format!("    \"{}\" -> \"{}\"{};", wire, op.output, style));
+        }
+    }
+
+    lines.push("}".to_string());
+    format!("{}\n", lines.join("\n"))
+}
+
+// Same graph as `to_dot` as a Mermaid flowchart. Node ids get a prefix since a three-letter
+// wire name like `end` would otherwise be read as a keyword.
+pub fn to_mermaid(circuit: &Circuit) -> String {
+    let flagged: HashSet<String> = flagged_wires(circuit)
+        .into_iter()
+        .map(|(wire, _)| wire)
+        .collect();
+    let id = |wire: &str| format!("w_{}", wire);
+    let mut lines = vec!["flowchart LR".to_string()];
+
+    lines.push("    subgraph inputs".to_string());
+    for wire in sorted_inputs(circuit) {
+        lines.push(format!("        {}[\"{}\"]", id(wire), wire));
+    }
+    lines.push("    end".to_string());
+
+    let gates = sorted_gates(circuit);
+    let node = |op: &Operation| {
+        format!(
+            "{}([\"{} {}\"]):::{}",
+            id(&op.output),
+            op.output,
+            op.operand,
+            op.operand.to_lowercase()
+        )
+    };
+    for op in gates.iter().filter(|op| !op.output.starts_with('z')) {
+        lines.push(format!("    {}", node(op)));
+    }
+    lines.push("    subgraph outputs".to_string());
+    for op in gates.iter().filter(|op| op.output.starts_with('z')) {
+        lines.push(format!("        {}", node(op)));
+    }
+    lines.push("    end".to_string());
+
+    // linkStyle refers to edges by the order they're declared in
+    let mut red_links = vec![];
+    for (idx, (wire, output)) in gates
+        .iter()
+        .flat_map(|op| op.inputs().into_iter().map(|wire| (wire, &op.output)))
+        .enumerate()
+    {
+        lines.push(format!("    {} --> {}", id(wire), id(output)));
+        if flagged.contains(wire) {
+            red_links.push(idx.to_string());
+        }
+    }
+
+    for operand in ["AND", "OR", "XOR", "NAND", "NOR", "XNOR", "NOT"] {
+        lines.push(format!(
+            "    classDef {} fill:{}",
+            operand.to_lowercase(),
+            fill_colour(operand)
+        ));
+    }
+    if !flagged.is_empty() {
+        let mut flagged: Vec<String> = flagged.iter().map(|wire| id(wire)).collect();
+        flagged.sort();
+        lines.push("    classDef flagged stroke:#f00,stroke-width:3px".to_string());
+        lines.push(format!("    class {} flagged", flagged.join(",")));
+    }
+    if !red_links.is_empty() {
+        lines.push(format!(
+            "    linkStyle {} stroke:#f00,stroke-width:2px",
+            red_links.join(",")
+        ));
+    }
+
+    format!("{}\n", lines.join("\n"))
+}
@@ -0,0 +1,723 @@
+use common::{Answer, Generator, Knob, Knobs, ParseError, Rng, Solution};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+mod export;
+mod simulate;
+mod swaps;
+
+pub use export::{to_dot, to_mermaid};
+pub use simulate::{input_bits, simulate, verify_adder, AdderMismatch};
+pub use swaps::{find_swaps, Swap, Unrepairable};
+
+pub const EXAMPLES: &[&str] = &[
+    include_str!("../example1.txt"),
+    include_str!("../example2.txt"),
+];
+
+pub struct Operation {
+    wire1: String,
+    wire2: String,
+    operand: String,
+    output: String,
+}
+
+impl Operation {
+    fn inputs(&self) -> Vec<&String> {
+        if self.operand == "NOT" {
+            vec![&self.wire1]
+        } else {
+            vec![&self.wire1, &self.wire2]
+        }
+    }
+}
+
+pub struct Circuit {
+    initial_wires: Vec<(String, bool)>,
+    gates: Vec<Operation>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CircuitError {
+    // Wires feeding each other in a loop, the first wire is read by the last one's gate
+    Cycle { wires: Vec<String> },
+    // A gate input that is neither an initial wire nor driven by a gate
+    UndefinedWire { wire: String, gate: String },
+    UnknownOperator { operand: String },
+    // More x, y or z wires than the 127 bits a number is held in
+    TooWide { bits: usize },
+}
+
+impl fmt::Display for CircuitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CircuitError::Cycle { wires } => {
+                write!(
+                    f,
+                    "Gates form a loop: {} -> {}",
+                    wires.join(" -> "),
+                    wires[0]
+                )
+            }
+            CircuitError::UndefinedWire { wire, gate } => write!(
+                f,
+                "Gate {} reads wire {}, which is neither an input nor driven by a gate",
+                gate, wire
+            ),
+            CircuitError::UnknownOperator { operand } => {
+                write!(f, "Unknown operation: {}", operand)
+            }
+            CircuitError::TooWide { bits } => write!(
+                f,
+                "The circuit has {}-bit numbers, at most 127 bits are supported",
+                bits
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CircuitError {}
+
+const BINARY_GATES: [&str; 6] = ["AND", "OR", "XOR", "NAND", "NOR", "XNOR"];
+
+// NOT reads a single wire, its operation keeps that wire as both inputs
+fn evaluate_gate(input1: bool, input2: bool, operand: &str) -> Result<bool, CircuitError> {
+    match operand {
+        "AND" => Ok(input1 && input2),
+        "OR" => Ok(input1 || input2),
+        "XOR" => Ok(input1 ^ input2),
+        "NAND" => Ok(!(input1 && input2)),
+        "NOR" => Ok(!(input1 || input2)),
+        "XNOR" => Ok(input1 == input2),
+        "NOT" => Ok(!input1),
+        _ => Err(CircuitError::UnknownOperator {
+            operand: operand.to_string(),
+        }),
+    }
+}
+
+fn to_i128(slice: &[u8]) -> i128 {
+    slice.iter().rev().fold(0, |acc, &b| acc * 2 + b as i128)
+}
+
+fn parse_circuit(input: &str) -> Result<Circuit, ParseError> {
+    let initial_wires = input
+        .lines()
+        .take_while(|line| !line.trim().is_empty())
+        .map(|line| {
+            let (key, value) = line
+                .split_once(':')
+                .ok_or_else(|| ParseError::at(input, &line[line.len()..], "':'"))?;
+            let value = match value.trim() {
+                "1" => true,
+                "0" => false,
+                other => return Err(ParseError::at(input, other, "0 or 1")),
+            };
+            Ok((key.trim().to_string(), value))
+        })
+        .collect::<Result<_, _>>()?;
+
+    let gates = input
+        .lines()
+        .skip_while(|line| !line.trim().is_empty())
+        .skip(1)
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let (expression, output) = line
+                .split_once(" -> ")
+                .ok_or_else(|| ParseError::at(input, &line[line.len()..], "' -> '"))?;
+            let mut operands = expression.split_whitespace();
+            let mut next = |expected: &str| {
+                operands
+                    .next()
+                    .ok_or_else(|| ParseError::at(input, &expression[expression.len()..], expected))
+            };
+            let first = next("input wire or NOT")?;
+            let (wire1, operand, wire2) = if first == "NOT" {
+                let wire = next("input wire")?;
+                (wire, first, wire)
+            } else {
+                let operand = next("gate")?;
+                if !BINARY_GATES.contains(&operand) {
+                    return Err(ParseError::at(
+                        input,
+                        operand,
+                        "AND, OR, XOR, NAND, NOR or XNOR",
+                    ));
+                }
+                (first, operand, next("input wire")?)
+            };
+            if let Some(extra) = operands.next() {
+                return Err(ParseError::at(input, extra, "' -> '"));
+            }
+
+            Ok(Operation {
+                wire1: wire1.to_string(),
+                wire2: wire2.to_string(),
+                operand: operand.to_string(),
+                output: output.trim().to_string(),
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Circuit {
+        initial_wires,
+        gates,
+    })
+}
+
+fn resolve_circut(circuit: &Circuit) -> Result<HashMap<&str, bool>, CircuitError> {
+    let wires: HashMap<&str, bool> = circuit
+        .initial_wires
+        .iter()
+        .map(|(key, value)| (key.as_str(), *value))
+        .collect();
+
+    resolve_wires(circuit, wires)
+}
+
+// Evaluates the gates in topological order, starting from the given input wires
+fn resolve_wires<'a>(
+    circuit: &'a Circuit,
+    mut wires: HashMap<&'a str, bool>,
+) -> Result<HashMap<&'a str, bool>, CircuitError> {
+    let drivers: HashMap<&str, usize> = circuit
+        .gates
+        .iter()
+        .enumerate()
+        .map(|(idx, op)| (op.output.as_str(), idx))
+        .collect();
+
+    // Number of inputs each gate still waits for and the gates reading each wire
+    let mut pending = vec![0; circuit.gates.len()];
+    let mut readers: HashMap<&str, Vec<usize>> = HashMap::new();
+    let mut ready = vec![];
+    for (idx, op) in circuit.gates.iter().enumerate() {
+        for input in [op.wire1.as_str(), op.wire2.as_str()] {
+            if wires.contains_key(input) {
+                continue;
+            }
+            if !drivers.contains_key(input) {
+                return Err(CircuitError::UndefinedWire {
+                    wire: input.to_string(),
+                    gate: op.output.clone(),
+                });
+            }
+            pending[idx] += 1;
+            readers.entry(input).or_default().push(idx);
+        }
+        if pending[idx] == 0 {
+            ready.push(idx);
+        }
+    }
+
+    let mut evaluated = 0;
+    while let Some(idx) = ready.pop() {
+        let op = &circuit.gates[idx];
+        let value = evaluate_gate(
+            wires[op.wire1.as_str()],
+            wires[op.wire2.as_str()],
+            &op.operand,
+        )?;
+        wires.insert(&op.output, value);
+        evaluated += 1;
+
+        for &reader in readers.get(op.output.as_str()).into_iter().flatten() {
+            pending[reader] -= 1;
+            if pending[reader] == 0 {
+                ready.push(reader);
+            }
+        }
+    }
+
+    if evaluated < circuit.gates.len() {
+        return Err(CircuitError::Cycle {
+            wires: find_cycle(circuit, &drivers, &pending),
+        });
+    }
+
+    Ok(wires)
+}
+
+// Every gate left waiting reads at least one wire of another waiting gate, so following those
+// wires from any of them has to come back around
+fn find_cycle(circuit: &Circuit, drivers: &HashMap<&str, usize>, pending: &[usize]) -> Vec<String> {
+    let waiting = |wire: &str| drivers.get(wire).filter(|&&idx| pending[idx] > 0).copied();
+
+    let mut idx = pending
+        .iter()
+        .position(|&count| count > 0)
+        .expect("Some gate is left waiting");
+    let mut path: Vec<&str> = vec![];
+    loop {
+        let op = &circuit.gates[idx];
+        if let Some(start) = path.iter().position(|&wire| wire == op.output) {
+            let mut cycle: Vec<String> =
+                path[start..].iter().map(|wire| wire.to_string()).collect();
+            // Listed in the direction the values flow, from the first wire by name
+            cycle.reverse();
+            let first = (0..cycle.len()).min_by_key(|&i| &cycle[i]).unwrap_or(0);
+            cycle.rotate_left(first);
+            return cycle;
+        }
+        path.push(&op.output);
+        idx = waiting(&op.wire1)
+            .or_else(|| waiting(&op.wire2))
+            .expect("A waiting gate reads a waiting wire");
+    }
+}
+
+fn solve_part1(circuit: &Circuit) -> Result<i128, CircuitError> {
+    let wires: HashMap<&str, bool> = resolve_circut(circuit)?;
+
+    let bits: Vec<u8> = (0..)
+        .map(|count| format!("z{:02}", count))
+        .map(|key| wires.get(key.as_str()).copied())
+        .take_while(Option::is_some)
+        .map(|wire| wire.unwrap() as u8)
+        .collect();
+
+    if bits.len() > 127 {
+        return Err(CircuitError::TooWide { bits: bits.len() });
+    }
+    Ok(to_i128(&bits))
+}
+
+fn format_wire(prefix: &str, num: u32) -> String {
+    format!("{}{:02}", prefix, num)
+}
+
+fn check_z(operations: &HashMap<&str, (&str, &str, &str)>, wire: &str, num: u32) -> bool {
+    if let Some((op, x, y)) = operations.get(wire) {
+        if *op != "XOR" {
+            return false;
+        }
+        if num == 0 {
+            let mut wires = vec![x, y];
+            wires.sort();
+            return wires == [&format_wire("x", 0), &format_wire("y", 0)];
+        }
+        return (check_intermediate_xor(operations, x, num) && check_carry(operations, y, num))
+            || (check_intermediate_xor(operations, y, num) && check_carry(operations, x, num));
+    }
+    false
+}
+
+fn check_intermediate_xor(
+    operations: &HashMap<&str, (&str, &str, &str)>,
+    wire: &str,
+    num: u32,
+) -> bool {
+    if let Some((op, x, y)) = operations.get(wire) {
+        if *op != "XOR" {
+            return false;
+        }
+        let mut wires = vec![x, y];
+        wires.sort();
+        return wires == [&format_wire("x", num), &format_wire("y", num)];
+    }
+    false
+}
+
+fn check_carry(operations: &HashMap<&str, (&str, &str, &str)>, wire: &str, num: u32) -> bool {
+    // Nothing carries into bit 0, so a circuit whose only output is z00 isn't an adder
+    if num == 0 {
+        return false;
+    }
+    if let Some((op, x, y)) = operations.get(wire) {
+        // Bit 0 is a half adder, so the carry out of it is its AND alone
+        if num == 1 {
+            return check_direct_carry(operations, wire, 0);
+        }
+        if *op != "OR" {
+            return false;
+        }
+        return (check_direct_carry(operations, x, num - 1)
+            && check_recarry(operations, y, num - 1))
+            || (check_direct_carry(operations, y, num - 1)
+                && check_recarry(operations, x, num - 1));
+    }
+    false
+}
+
+fn check_direct_carry(
+    operations: &HashMap<&str, (&str, &str, &str)>,
+    wire: &str,
+    num: u32,
+) -> bool {
+    if let Some((op, x, y)) = operations.get(wire) {
+        if *op != "AND" {
+            return false;
+        }
+        let mut wires = vec![x, y];
+        wires.sort();
+        return wires == [&format_wire("x", num), &format_wire("y", num)];
+    }
+    false
+}
+
+fn check_recarry(operations: &HashMap<&str, (&str, &str, &str)>, wire: &str, num: u32) -> bool {
+    if let Some((op, x, y)) = operations.get(wire) {
+        if *op != "AND" {
+            return false;
+        }
+        return (check_intermediate_xor(operations, x, num) && check_carry(operations, y, num))
+            || (check_intermediate_xor(operations, y, num) && check_carry(operations, x, num));
+    }
+    false
+}
+
+// Parts of the full adder of a bit, as checked by `check_z` and `check_carry`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdderPart {
+    // x XOR y of the bit
+    HalfAdder,
+    // The z output, XOR of the half adder and the carry
+    Sum,
+    // Carry into the bit: OR of the direct carry and the recarry of the bit below
+    Carry,
+    // AND of the half adder and the carry of the bit below
+    Recarry,
+}
+
+// Lowest output bit whose adder doesn't have the ripple-carry shape
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Malformed {
+    pub bit: u32,
+    pub part: AdderPart,
+}
+
+impl fmt::Display for AdderPart {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let part = match self {
+            AdderPart::HalfAdder => "half adder",
+            AdderPart::Sum => "sum",
+            AdderPart::Carry => "carry",
+            AdderPart::Recarry => "recarry",
+        };
+        write!(f, "{}", part)
+    }
+}
+
+impl fmt::Display for Malformed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "The {} of {} is malformed",
+            self.part,
+            format_wire("z", self.bit)
+        )
+    }
+}
+
+fn operations(circuit: &Circuit) -> HashMap<&str, (&str, &str, &str)> {
+    circuit
+        .gates
+        .iter()
+        .map(|op| {
+            (
+                op.output.as_str(),
+                (op.operand.as_str(), op.wire1.as_str(), op.wire2.as_str()),
+            )
+        })
+        .collect()
+}
+
+// Width of the x and y inputs, the z output above them is the carry out of the last adder
+fn adder_bits(operations: &HashMap<&str, (&str, &str, &str)>) -> u32 {
+    let outputs = operations
+        .keys()
+        .filter(|wire| wire.starts_with('z'))
+        .count() as u32;
+    outputs.saturating_sub(1)
+}
+
+fn check_output(operations: &HashMap<&str, (&str, &str, &str)>, bit: u32, bits: u32) -> bool {
+    let wire = format_wire("z", bit);
+    if bit == bits {
+        check_carry(operations, &wire, bit)
+    } else {
+        check_z(operations, &wire, bit)
+    }
+}
+
+// Number of output bits, from z00 up, with a well-formed adder
+fn progress(operations: &HashMap<&str, (&str, &str, &str)>, bits: u32) -> u32 {
+    (0..=bits)
+        .find(|&bit| !check_output(operations, bit, bits))
+        .unwrap_or(bits + 1)
+}
+
+// Narrows a failing output down to the part of its adder that's wrong, assuming every bit below
+// is well-formed
+fn malformed_part(
+    operations: &HashMap<&str, (&str, &str, &str)>,
+    bit: u32,
+    bits: u32,
+) -> AdderPart {
+    let z = format_wire("z", bit);
+    if bit == 0 {
+        return AdderPart::HalfAdder;
+    }
+    if bit == bits {
+        return carry_part(operations, &z, bit);
+    }
+
+    match operations.get(z.as_str()) {
+        Some(&("XOR", x, y)) => {
+            let carry = if check_intermediate_xor(operations, x, bit) {
+                y
+            } else if check_intermediate_xor(operations, y, bit) {
+                x
+            } else {
+                return AdderPart::HalfAdder;
+            };
+            carry_part(operations, carry, bit)
+        }
+        _ => AdderPart::Sum,
+    }
+}
+
+fn carry_part(operations: &HashMap<&str, (&str, &str, &str)>, wire: &str, bit: u32) -> AdderPart {
+    match operations.get(wire) {
+        Some(&("OR", x, y)) if bit > 1 => {
+            if check_direct_carry(operations, x, bit - 1) && !check_recarry(operations, y, bit - 1)
+                || check_direct_carry(operations, y, bit - 1)
+                    && !check_recarry(operations, x, bit - 1)
+            {
+                AdderPart::Recarry
+            } else {
+                AdderPart::Carry
+            }
+        }
+        _ => AdderPart::Carry,
+    }
+}
+
+// Wire of the gate a malformed stage goes wrong at: the carry into the bit for a carry or recarry
+// fault, its z output otherwise
+fn malformed_wire(
+    operations: &HashMap<&str, (&str, &str, &str)>,
+    malformed: Malformed,
+    bits: u32,
+) -> String {
+    let z = format_wire("z", malformed.bit);
+    if !matches!(malformed.part, AdderPart::Carry | AdderPart::Recarry) || malformed.bit == bits {
+        return z;
+    }
+    match operations.get(z.as_str()) {
+        Some(&("XOR", x, y)) if check_intermediate_xor(operations, x, malformed.bit) => {
+            y.to_string()
+        }
+        Some(&("XOR", x, _)) => x.to_string(),
+        _ => z,
+    }
+}
+
+// First output bit whose full adder is malformed, None for a well-formed ripple-carry adder of
+// any width
+pub fn check_adder(circuit: &Circuit) -> Option<Malformed> {
+    let operations = operations(circuit);
+    let bits = adder_bits(&operations);
+    let bit = progress(&operations, bits);
+    (bit <= bits).then(|| Malformed {
+        bit,
+        part: malformed_part(&operations, bit, bits),
+    })
+}
+
+fn solve_part2(circuit: &Circuit) -> Result<String, Unrepairable> {
+    let mut wires: Vec<String> = find_swaps(circuit)?
+        .into_iter()
+        .flat_map(|swap| [swap.wires.0, swap.wires.1])
+        .collect();
+    wires.sort();
+    Ok(wires.join(","))
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Circuit;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_circuit(input)
+    }
+
+    fn part1(circuit: &Self::Input) -> Answer {
+        solve_part1(circuit).into()
+    }
+
+    fn part2(circuit: &Self::Input) -> Answer {
+        solve_part2(circuit).into()
+    }
+}
+
+// Swapped stages are two bits apart and never the last of the `bits - 1` full adders
+fn max_swaps(bits: usize) -> usize {
+    (bits - 1) / 2
+}
+
+// Gate inputs, operation and output wire
+type Gate = [String; 4];
+
+fn has_cycle(gates: &[Gate]) -> bool {
+    let inputs: HashMap<&str, [&str; 2]> = gates
+        .iter()
+        .map(|[wire1, _, wire2, output]| (output.as_str(), [wire1.as_str(), wire2.as_str()]))
+        .collect();
+
+    // Depth-first search keeping track of the wires on the current path
+    fn visit<'a>(
+        wire: &'a str,
+        inputs: &HashMap<&'a str, [&'a str; 2]>,
+        on_path: &mut HashSet<&'a str>,
+        done: &mut HashSet<&'a str>,
+    ) -> bool {
+        if done.contains(wire) {
+            return false;
+        }
+        if !on_path.insert(wire) {
+            return true;
+        }
+        let cyclic = inputs.get(wire).is_some_and(|wires| {
+            wires
+                .iter()
+                .any(|&input| visit(input, inputs, on_path, done))
+        });
+        on_path.remove(wire);
+        done.insert(wire);
+        cyclic
+    }
+
+    let mut on_path = HashSet::new();
+    let mut done = HashSet::new();
+    inputs
+        .keys()
+        .any(|&wire| visit(wire, &inputs, &mut on_path, &mut done))
+}
+
+// `i` has to come before `j`
+fn swap_outputs(gates: &mut [Gate], i: usize, j: usize) {
+    let (head, tail) = gates.split_at_mut(j);
+    std::mem::swap(&mut head[i][3], &mut tail[0][3]);
+}
+
+impl Generator for Day24 {
+    const KNOBS: &'static [Knob] = &[
+        Knob {
+            name: "bits",
+            default: 45,
+            range: 9..=99,
+            help: "width of the x and y inputs",
+        },
+        Knob {
+            name: "swaps",
+            default: 4,
+            range: 0..=22,
+            help: "pairs of gate outputs swapped, each inside a different full adder",
+        },
+    ];
+
+    fn check(knobs: &Knobs) -> Result<(), String> {
+        let (bits, swaps) = (knobs.get("bits"), knobs.get("swaps"));
+        if swaps > max_swaps(bits) {
+            return Err(format!(
+                "Knob swaps can be at most {} with {} bits, swapped stages are two bits apart \
+                 and the last one is left alone",
+                max_swaps(bits),
+                bits
+            ));
+        }
+        Ok(())
+    }
+
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        let bits = knobs.get("bits") as u32;
+
+        // Internal wires get three letters that can't be mistaken for x, y or z wires
+        let mut names: Vec<String> = ('a'..='w')
+            .flat_map(|a| {
+                ('a'..='z').flat_map(move |b| ('a'..='z').map(move |c| format!("{}{}{}", a, b, c)))
+            })
+            .collect();
+        rng.shuffle(&mut names);
+        let mut fresh = names.into_iter();
+        let mut next_name = || fresh.next().expect("Enough wire names for 99 bits");
+
+        let gate = |a: &str, op: &str, b: &str, out: &str| [a, op, b, out].map(str::to_string);
+
+        // Ripple-carry adder, every stage after the first is a full adder of five gates:
+        // the XOR and AND of the inputs, the sum bit, the carried AND and the carry OR
+        let mut gates = vec![
+            gate("x00", "XOR", "y00", "z00"),
+            gate("x00", "AND", "y00", &next_name()),
+        ];
+        let mut stages = vec![];
+        let mut carry = gates[1][3].clone();
+        for bit in 1..bits {
+            let [x, y, z] = ["x", "y", "z"].map(|prefix| format_wire(prefix, bit));
+            let (sum, direct, carried) = (next_name(), next_name(), next_name());
+            let carry_out = if bit + 1 == bits {
+                format_wire("z", bits)
+            } else {
+                next_name()
+            };
+
+            stages.push(gates.len()..gates.len() + 5);
+            gates.extend([
+                gate(&x, "XOR", &y, &sum),
+                gate(&x, "AND", &y, &direct),
+                gate(&sum, "XOR", &carry, &z),
+                gate(&sum, "AND", &carry, &carried),
+                gate(&direct, "OR", &carried, &carry_out),
+            ]);
+            carry = carry_out;
+        }
+
+        // Swaps go into stages at least two bits apart, the last one is left alone as its carry
+        // is the top output bit. Picking the stages out of a range shortened by one per swap
+        // and spreading them back out keeps that gap.
+        let swaps = knobs.get("swaps");
+        let mut picked: Vec<usize> = (0..stages.len() - swaps).collect();
+        rng.shuffle(&mut picked);
+        picked.truncate(swaps);
+        picked.sort();
+        for (i, stage) in picked.into_iter().enumerate() {
+            let stage = &stages[stage + i];
+
+            // Swapping the two ANDs feeding the carry OR changes nothing, skip that pair and any
+            // swap that would feed a gate its own output. Swapping the two input gates is always
+            // left, so every stage gets its swap.
+            let mut pairs: Vec<(usize, usize)> = (0..5)
+                .flat_map(|i| (i + 1..5).map(move |j| (i, j)))
+                .filter(|&pair| pair != (1, 3))
+                .collect();
+            rng.shuffle(&mut pairs);
+            for (i, j) in pairs {
+                let (i, j) = (stage.start + i, stage.start + j);
+                swap_outputs(&mut gates, i, j);
+                if !has_cycle(&gates) {
+                    break;
+                }
+                swap_outputs(&mut gates, i, j);
+            }
+        }
+
+        let mut lines = vec![];
+        for prefix in ["x", "y"] {
+            for bit in 0..bits {
+                lines.push(format!("{}: {}", format_wire(prefix, bit), rng.below(2)));
+            }
+        }
+        lines.push(String::new());
+
+        rng.shuffle(&mut gates);
+        for [a, op, b, out] in gates {
+            let (a, b) = if rng.chance(50) { (a, b) } else { (b, a) };
+            lines.push(format!("{} {} {} -> {}", a, op, b, out));
+        }
+
+        format!("{}\n", lines.join("\n"))
+    }
+}
@@ -0,0 +1,98 @@
+use common::Rng;
+use std::collections::HashMap;
+
+use crate::{format_wire, resolve_wires, Circuit, CircuitError};
+
+// Sum of two inputs the circuit computed wrong, `bit` is the lowest bit of z that's off
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdderMismatch {
+    pub x: u128,
+    pub y: u128,
+    pub z: u128,
+    pub bit: u32,
+}
+
+// Width of the x and y inputs, taken from the x wires of the puzzle input
+pub fn input_bits(circuit: &Circuit) -> u32 {
+    circuit
+        .initial_wires
+        .iter()
+        .filter(|(wire, _)| wire.starts_with('x'))
+        .count() as u32
+}
+
+fn output_bits(circuit: &Circuit) -> u32 {
+    circuit
+        .gates
+        .iter()
+        .filter(|op| op.output.starts_with('z'))
+        .count() as u32
+}
+
+// x, y and z are held in a u128, like part 1 a number of at most 127 bits
+fn check_width(circuit: &Circuit) -> Result<(), CircuitError> {
+    let bits = input_bits(circuit).max(output_bits(circuit));
+    if bits > 127 {
+        return Err(CircuitError::TooWide {
+            bits: bits as usize,
+        });
+    }
+    Ok(())
+}
+
+// Runs the circuit on x and y instead of the input's initial wire values and reads z back
+pub fn simulate(circuit: &Circuit, x: u128, y: u128) -> Result<u128, CircuitError> {
+    check_width(circuit)?;
+    let bits = input_bits(circuit);
+    let names: Vec<(String, bool)> = (0..bits)
+        .flat_map(|bit| {
+            [
+                (format_wire("x", bit), (x >> bit) & 1 == 1),
+                (format_wire("y", bit), (y >> bit) & 1 == 1),
+            ]
+        })
+        .collect();
+    let wires: HashMap<&str, bool> = names
+        .iter()
+        .map(|(wire, value)| (wire.as_str(), *value))
+        .collect();
+
+    let wires = resolve_wires(circuit, wires)?;
+    Ok((0..output_bits(circuit))
+        .filter(|&bit| wires.get(format_wire("z", bit).as_str()) == Some(&true))
+        .fold(0, |z, bit| z | 1 << bit))
+}
+
+fn random_input(rng: &mut Rng, bits: u32) -> u128 {
+    let value = (rng.next_u64() as u128) << 64 | rng.next_u64() as u128;
+    value & ((1 << bits) - 1)
+}
+
+// Checks z == x + y on `samples` random inputs, returning the failure with the lowest wrong bit
+// or None when every sample adds up
+pub fn verify_adder(
+    circuit: &Circuit,
+    samples: usize,
+    rng: &mut Rng,
+) -> Result<Option<AdderMismatch>, CircuitError> {
+    check_width(circuit)?;
+    let bits = input_bits(circuit);
+    let z_mask = (1u128 << output_bits(circuit)) - 1;
+
+    let mut lowest: Option<AdderMismatch> = None;
+    for _ in 0..samples {
+        let (x, y) = (random_input(rng, bits), random_input(rng, bits));
+        let z = simulate(circuit, x, y)?;
+        let wrong = (z ^ (x + y)) & z_mask;
+        if wrong == 0 {
+            continue;
+        }
+
+        let bit = wrong.trailing_zeros();
+        if lowest.as_ref().is_none_or(|mismatch| bit < mismatch.bit) {
+            lowest = Some(AdderMismatch { x, y, z, bit });
+        }
+    }
+
+    Ok(lowest)
+}
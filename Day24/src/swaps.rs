@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::{
+    adder_bits, format_wire, malformed_part, malformed_wire, operations, progress, Circuit,
+    Malformed,
+};
+
+type Operations<'a> = HashMap<&'a str, (&'a str, &'a str, &'a str)>;
+
+// The lowest malformed stage left once the swaps found before it are made, when no swap near it
+// fixes it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Unrepairable {
+    pub malformed: Malformed,
+}
+
+impl fmt::Display for Unrepairable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "No swap near {} fixes its {}",
+            format_wire("z", self.malformed.bit),
+            self.malformed.part
+        )
+    }
+}
+
+// Gates a wire can be at most this many gates away from the x and y inputs of the failing bit,
+// or of the bit below it, to be a swap candidate. The carry into a bit is three gates past the
+// inputs of the bit below it, and its sum one more.
+const STAGE_DEPTH: usize = 4;
+
+// Two gates whose output wires were swapped, and the stage of the adder swapping them back fixes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Swap {
+    pub wires: (String, String),
+    pub fixes: Malformed,
+}
+
+impl fmt::Display for Swap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} <-> {} fixes the {} of {}",
+            self.wires.0,
+            self.wires.1,
+            self.fixes.part,
+            format_wire("z", self.fixes.bit)
+        )
+    }
+}
+
+// Gate outputs reachable from the inputs of `bit` and the bit below it, plus the bit's own z
+fn neighbourhood<'a>(operations: &Operations<'a>, bit: u32) -> Vec<&'a str> {
+    let mut readers: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (&output, &(_, x, y)) in operations {
+        readers.entry(x).or_default().push(output);
+        if y != x {
+            readers.entry(y).or_default().push(output);
+        }
+    }
+
+    let mut inputs = vec![];
+    for num in bit.saturating_sub(1)..=bit {
+        inputs.push(format_wire("x", num));
+        inputs.push(format_wire("y", num));
+    }
+    let mut frontier: Vec<&str> = inputs.iter().map(|wire| wire.as_str()).collect();
+    let mut wires: Vec<&'a str> = vec![];
+    for _ in 0..STAGE_DEPTH {
+        let next: Vec<&'a str> = frontier
+            .iter()
+            .filter_map(|wire| readers.get(wire))
+            .flatten()
+            .copied()
+            .filter(|wire| !wires.contains(wire))
+            .collect();
+        wires.extend(&next);
+        wires.sort();
+        wires.dedup();
+        frontier = next;
+    }
+
+    let z = format_wire("z", bit);
+    if let Some((&wire, _)) = operations.get_key_value(z.as_str()) {
+        if !wires.contains(&wire) {
+            wires.push(wire);
+        }
+    }
+    wires.sort();
+    wires
+}
+
+fn swap<'a>(operations: &mut Operations<'a>, x: &'a str, y: &'a str) {
+    let gate_x = operations[x];
+    let gate_y = operations[y];
+    operations.insert(x, gate_y);
+    operations.insert(y, gate_x);
+}
+
+// Repairs the adder one stage at a time. At the lowest malformed bit every pair of gates near
+// that bit is swapped, keeping the swap that leaves the most bits well-formed. Stops at the first
+// malformed bit no swap near it helps, returned along with the swaps made before it.
+fn repair(operations: &mut Operations, bits: u32) -> (Vec<Swap>, Option<Malformed>) {
+    let mut swaps = vec![];
+
+    loop {
+        let baseline = progress(operations, bits);
+        if baseline > bits {
+            return (swaps, None);
+        }
+
+        let candidates = neighbourhood(operations, baseline);
+        let mut best: Option<(u32, &str, &str)> = None;
+        for (i, &x) in candidates.iter().enumerate() {
+            for &y in &candidates[i + 1..] {
+                swap(operations, x, y);
+                let fixed = progress(operations, bits);
+                swap(operations, x, y);
+                if fixed > baseline && best.is_none_or(|(most, _, _)| fixed > most) {
+                    best = Some((fixed, x, y));
+                }
+            }
+        }
+
+        let fixes = Malformed {
+            bit: baseline,
+            part: malformed_part(operations, baseline, bits),
+        };
+        let Some((_, x, y)) = best else {
+            return (swaps, Some(fixes));
+        };
+        swap(operations, x, y);
+        swaps.push(Swap {
+            wires: (x.to_string(), y.to_string()),
+            fixes,
+        });
+    }
+}
+
+// Swaps that repair the adder, failing at the first malformed bit no swap near it helps
+pub fn find_swaps(circuit: &Circuit) -> Result<Vec<Swap>, Unrepairable> {
+    let mut operations = operations(circuit);
+    let bits = adder_bits(&operations);
+    match repair(&mut operations, bits) {
+        (swaps, None) => Ok(swaps),
+        (_, Some(malformed)) => Err(Unrepairable { malformed }),
+    }
+}
+
+// Wires drawn in red by the export, each with the reason why: both wires of every swap
+// `find_swaps` makes, then the gate a stage no swap repairs goes wrong at
+pub(crate) fn flagged_wires(circuit: &Circuit) -> Vec<(String, String)> {
+    let mut operations = operations(circuit);
+    let bits = adder_bits(&operations);
+    let (swaps, stuck) = repair(&mut operations, bits);
+
+    let mut flagged = vec![];
+    for swap in swaps {
+        let reason = swap.to_string();
+        flagged.push((swap.wires.0, reason.clone()));
+        flagged.push((swap.wires.1, reason));
+    }
+    if let Some(malformed) = stuck {
+        flagged.push((
+            malformed_wire(&operations, malformed, bits),
+            Unrepairable { malformed }.to_string(),
+        ));
+    }
+    flagged
+}
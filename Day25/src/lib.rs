@@ -0,0 +1,166 @@
+use common::{Answer, Generator, Knob, Knobs, ParseError, Rng, Solution};
+
+pub const EXAMPLES: &[&str] = &[include_str!("../example1.txt")];
+
+// Pin heights of every lock or key
+type Heights = Vec<Vec<isize>>;
+
+fn check_row(input: &str, row: &str, width: usize) -> Result<(), ParseError> {
+    if let Some((idx, _)) = row.char_indices().find(|&(_, c)| c != '#' && c != '.') {
+        return Err(ParseError::at(input, &row[idx..], "'#' or '.'"));
+    }
+    if row.len() != width {
+        let end = row.len().min(width);
+        return Err(ParseError::at(
+            input,
+            &row[end..],
+            format!("row of width {}", width),
+        ));
+    }
+    Ok(())
+}
+
+fn parse(input: &str) -> Result<(Heights, Heights), ParseError> {
+    let mut it = input.lines().peekable();
+
+    let mut locks: Heights = vec![];
+    let mut keys: Heights = vec![];
+
+    while it.peek().is_some() {
+        if let Some(line) = it.next() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            if line.chars().all(|c| c == '#') {
+                let mut lock = vec![0; line.len()];
+                while let Some(next_line) = it.peek() {
+                    if next_line.trim().is_empty() {
+                        break;
+                    }
+                    check_row(input, next_line, line.len())?;
+                    for (i, c) in next_line.chars().enumerate() {
+                        if c == '#' {
+                            lock[i] += 1;
+                        }
+                    }
+                    it.next();
+                }
+                locks.push(lock);
+            } else if line.chars().all(|c| c == '.') {
+                let mut key = vec![-1; line.len()];
+                let mut temp_stack = vec![];
+                while let Some(next_line) = it.peek() {
+                    if next_line.trim().is_empty() {
+                        break;
+                    }
+                    check_row(input, next_line, line.len())?;
+                    temp_stack.push(next_line.to_string());
+                    it.next();
+                }
+                for stacked_line in temp_stack.iter().rev() {
+                    for (i, c) in stacked_line.chars().enumerate() {
+                        if c == '#' {
+                            key[i] += 1;
+                        }
+                    }
+                }
+                keys.push(key);
+            } else {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    "lock top '#####' or key top '.....'",
+                ));
+            }
+        }
+    }
+
+    Ok((locks, keys))
+}
+
+fn count_fitting_pairs(locks: &[Vec<isize>], keys: &[Vec<isize>]) -> usize {
+    let mut count = 0;
+    for lock in locks {
+        for key in keys {
+            if lock.iter().zip(key).all(|(&l, &k)| l + k <= 5) {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = (Heights, Heights);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1((locks, keys): &Self::Input) -> Answer {
+        count_fitting_pairs(locks, keys).into()
+    }
+
+    // Day 25 has no second puzzle
+    fn part2(_: &Self::Input) -> Answer {
+        Answer::None
+    }
+}
+
+impl Generator for Day25 {
+    const KNOBS: &'static [Knob] = &[
+        Knob {
+            name: "locks",
+            default: 250,
+            range: 0..=100_000,
+            help: "lock schematics",
+        },
+        Knob {
+            name: "keys",
+            default: 250,
+            range: 0..=100_000,
+            help: "key schematics",
+        },
+    ];
+
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        // 5 pins of height 0 to 5 in a 7 row schematic whose first and last rows are fixed
+        fn schematic(rng: &mut Rng, is_lock: bool) -> String {
+            let heights: Vec<usize> = (0..5).map(|_| rng.range(0..6)).collect();
+            let mut rows = vec![];
+            for row in 0..7 {
+                let row: String = heights
+                    .iter()
+                    .map(|&height| {
+                        let filled = if is_lock {
+                            row <= height
+                        } else {
+                            6 - row <= height
+                        };
+                        if filled {
+                            '#'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect();
+                rows.push(row);
+            }
+            rows.join("\n")
+        }
+
+        let mut is_lock = vec![true; knobs.get("locks")];
+        is_lock.resize(is_lock.len() + knobs.get("keys"), false);
+        rng.shuffle(&mut is_lock);
+
+        let schematics: Vec<String> = is_lock
+            .into_iter()
+            .map(|is_lock| schematic(rng, is_lock))
+            .collect();
+
+        format!("{}\n", schematics.join("\n\n"))
+    }
+}
@@ -0,0 +1,104 @@
+use common::{Answer, Generator, Knob, Knobs, ParseError, Rng, Solution};
+use regex::Regex;
+
+pub const EXAMPLES: &[&str] = &[
+    include_str!("../example1.txt"),
+    include_str!("../example2.txt"),
+];
+
+fn solve(content: &str, re: &Regex, solve_part2: bool) -> i32 {
+    let mut allow_mul = true;
+    let mut counter: i32 = 0;
+
+    for caps in re.captures_iter(content) {
+        if let Some(matched) = caps.get(0) {
+            let text = matched.as_str();
+            if solve_part2 {
+                if text == "do()" {
+                    allow_mul = true;
+                } else if text == "don't()" {
+                    allow_mul = false;
+                }
+            }
+            if (!solve_part2 || allow_mul) && text.starts_with("mul(") {
+                let x: i32 = caps.get(1).unwrap().as_str().parse().unwrap();
+                let y: i32 = caps.get(2).unwrap().as_str().parse().unwrap();
+                counter += x * y;
+            }
+        }
+    }
+
+    counter
+}
+
+fn instruction_regex() -> Regex {
+    Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)|do\(\)|don't\(\)").unwrap()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve(input, &instruction_regex(), false).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve(input, &instruction_regex(), true).into()
+    }
+}
+
+impl Generator for Day3 {
+    const KNOBS: &'static [Knob] = &[
+        Knob {
+            name: "lines",
+            default: 6,
+            range: 1..=10,
+            help: "lines of corrupted memory",
+        },
+        Knob {
+            name: "instructions",
+            default: 120,
+            range: 0..=300,
+            help: "mul, do and don't instructions per line, corrupted ones included",
+        },
+    ];
+
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        const NOISE: &[&str] = &[
+            "from()", "when()", "why()", "how()", "select()", "who()", "where()", "what()", "mul",
+            "mul(", "don't", "do(", "%", "&", "^", "[", "]", "{", "}", "'", "<", ">", "?", ",",
+            ";", ":", "@", "#", "$", "*", "+", "-", "~", "!", " ",
+        ];
+        let mut output = String::new();
+
+        for _ in 0..knobs.get("lines") {
+            for _ in 0..knobs.get("instructions") {
+                for _ in 0..rng.range(0..6) {
+                    let noise = *rng.pick(NOISE);
+                    output.push_str(noise);
+                }
+
+                let (x, y) = (1 + rng.below(999), 1 + rng.below(999));
+                let instruction = match rng.range(0..10) {
+                    0 => "do()".to_string(),
+                    1 => "don't()".to_string(),
+                    // Corrupted multiplications the solver has to skip
+                    2 => format!("mul({}, {})", x, y),
+                    3 => format!("mul[{},{}]", x, y),
+                    4 => format!("mul({},{}", x, y),
+                    _ => format!("mul({},{})", x, y),
+                };
+                output.push_str(&instruction);
+            }
+            output.push('\n');
+        }
+
+        output
+    }
+}
@@ -0,0 +1,123 @@
+use common::{Answer, Dir, Generator, Grid, Knob, Knobs, ParseError, Pos, Rng, Solution};
+
+pub const EXAMPLES: &[&str] = &[include_str!("../example1.txt")];
+
+fn is_valid_xmas(grid: &Grid<char>, pos: Pos, dir1: Dir, dir2: Dir) -> bool {
+    let chars: Vec<char> = [dir1, dir2]
+        .iter()
+        .filter_map(|&dir| grid.step(pos, dir))
+        .map(|neighbour| grid[neighbour])
+        .collect();
+
+    chars == ['M', 'S'] || chars == ['S', 'M']
+}
+
+fn solve_part1(grid: &Grid<char>) -> u32 {
+    let word = "XMAS";
+    let mut count = 0;
+
+    for start in grid.find_all(&'X') {
+        for dir in Dir::ALL {
+            let mut pos = Some(start);
+            let mut found = true;
+
+            for ch in word.chars() {
+                match pos {
+                    Some(p) if grid[p] == ch => pos = grid.step(p, dir),
+                    _ => {
+                        found = false;
+                        break;
+                    }
+                }
+            }
+
+            if found {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+fn solve_part2(grid: &Grid<char>) -> u32 {
+    let directions_part2 = [(Dir::UpLeft, Dir::DownRight), (Dir::UpRight, Dir::DownLeft)];
+
+    let mut count = 0;
+
+    for pos in grid.find_all(&'A') {
+        if directions_part2
+            .iter()
+            .all(|&(dir1, dir2)| is_valid_xmas(grid, pos, dir1, dir2))
+        {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input, "one of X, M, A or S", |c| {
+        "XMAS".contains(c).then_some(c)
+    })
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input).into()
+    }
+}
+
+impl Generator for Day4 {
+    const KNOBS: &'static [Knob] = &[
+        Knob {
+            name: "size",
+            default: 140,
+            range: 1..=1000,
+            help: "width and height of the word search",
+        },
+        Knob {
+            name: "words",
+            default: 400,
+            range: 0..=100_000,
+            help: "XMAS words written over the random letters",
+        },
+    ];
+
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        let size = knobs.get("size");
+        let mut grid = Grid::new(size, size, 'X');
+        for pos in grid.positions().collect::<Vec<_>>() {
+            grid[pos] = *rng.pick(&['X', 'M', 'A', 'S']);
+        }
+
+        for _ in 0..knobs.get("words") {
+            let mut pos = Pos::new(rng.range(0..size), rng.range(0..size));
+            let dir = *rng.pick(&Dir::ALL);
+
+            // Words running off the edge are cut short
+            for letter in "XMAS".chars() {
+                grid[pos] = letter;
+                match grid.step(pos, dir) {
+                    Some(next) => pos = next,
+                    None => break,
+                }
+            }
+        }
+
+        grid.to_string()
+    }
+}
@@ -0,0 +1,163 @@
+use common::{parse_token, Answer, Generator, Knob, Knobs, ParseError, Rng, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub const EXAMPLES: &[&str] = &[include_str!("../example1.txt")];
+
+type Rules = HashMap<i32, HashSet<i32>>;
+// Rules, indices of the incorrectly-ordered updates and the updates themselves
+type Manual = (Rules, HashSet<usize>, Vec<Vec<i32>>);
+
+fn process_input(input: &str) -> Result<Manual, ParseError> {
+    let mut rules: Rules = HashMap::new();
+    let mut pages_idx = 0;
+
+    for (idx, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            pages_idx = idx;
+            break;
+        }
+        let (start, end) = line
+            .split_once('|')
+            .ok_or_else(|| ParseError::at(input, line, "page ordering rule X|Y"))?;
+        let start: i32 = parse_token(input, start, "page number")?;
+        let end: i32 = parse_token(input, end, "page number")?;
+        rules.entry(start).or_default().insert(end);
+    }
+
+    let mut banned_pages_indices: HashSet<usize> = HashSet::new();
+    let mut updates: Vec<Vec<i32>> = vec![];
+
+    for (page_idx, page_list) in input.lines().skip(pages_idx + 1).enumerate() {
+        let page_vec: Vec<i32> = page_list
+            .split(',')
+            .map(|x| parse_token(input, x, "page number"))
+            .collect::<Result<_, _>>()?;
+        updates.push(page_vec);
+
+        let mut is_banned = false;
+        for (idx, &page) in updates[page_idx].iter().enumerate() {
+            if let Some(banned_rules) = rules.get(&page) {
+                if updates[page_idx][..idx]
+                    .iter()
+                    .any(|prev_val| banned_rules.contains(prev_val))
+                {
+                    is_banned = true;
+                    break;
+                }
+            }
+        }
+
+        if is_banned {
+            banned_pages_indices.insert(page_idx);
+        }
+    }
+
+    Ok((rules, banned_pages_indices, updates))
+}
+
+fn solve_part1(banned_pages_indices: &HashSet<usize>, updates: &[Vec<i32>]) -> i32 {
+    let mut total_middle_sum = 0;
+
+    for (idx, page_list) in updates.iter().enumerate() {
+        if !banned_pages_indices.contains(&idx) {
+            total_middle_sum += page_list[page_list.len() / 2];
+        }
+    }
+
+    total_middle_sum
+}
+
+fn solve_part2(rules: &Rules, banned_pages_indices: &HashSet<usize>, updates: &[Vec<i32>]) -> i32 {
+    let mut total_middle_sum = 0;
+
+    for idx in banned_pages_indices {
+        let mut page_vec = updates[*idx].clone();
+        page_vec.sort_by(|a, b| {
+            if rules.get(a).is_some_and(|rule| rule.contains(b)) {
+                std::cmp::Ordering::Less
+            } else if rules.get(b).is_some_and(|rule| rule.contains(a)) {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        });
+
+        total_middle_sum += page_vec[page_vec.len() / 2];
+    }
+
+    total_middle_sum
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Manual;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        process_input(input)
+    }
+
+    fn part1((_, banned_pages_indices, updates): &Self::Input) -> Answer {
+        solve_part1(banned_pages_indices, updates).into()
+    }
+
+    fn part2((rules, banned_pages_indices, updates): &Self::Input) -> Answer {
+        solve_part2(rules, banned_pages_indices, updates).into()
+    }
+}
+
+impl Generator for Day5 {
+    const KNOBS: &'static [Knob] = &[
+        Knob {
+            name: "pages",
+            default: 49,
+            range: 2..=90,
+            help: "distinct page numbers, every pair of them gets an ordering rule",
+        },
+        Knob {
+            name: "updates",
+            default: 200,
+            range: 1..=100_000,
+            help: "number of updates",
+        },
+        Knob {
+            name: "max_update",
+            default: 23,
+            range: 1..=90,
+            help: "most pages in a single update",
+        },
+    ];
+
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        // A hidden total order over two-digit page numbers, the rules spell out every pair of it
+        let mut order: Vec<usize> = (10..100).collect();
+        rng.shuffle(&mut order);
+        order.truncate(knobs.get("pages"));
+
+        let mut rules = vec![];
+        for (i, before) in order.iter().enumerate() {
+            for after in &order[i + 1..] {
+                rules.push(format!("{}|{}", before, after));
+            }
+        }
+        rng.shuffle(&mut rules);
+
+        let max_update = knobs.get("max_update").min(order.len());
+        let mut updates = vec![];
+        for _ in 0..knobs.get("updates") {
+            // Odd lengths so every update has a middle page
+            let length = rng.range(0..max_update.div_ceil(2)) * 2 + 1;
+            let mut indices: Vec<usize> = (0..order.len()).collect();
+            rng.shuffle(&mut indices);
+            indices.truncate(length);
+
+            if rng.chance(50) {
+                indices.sort();
+            }
+            let pages: Vec<String> = indices.iter().map(|&i| order[i].to_string()).collect();
+            updates.push(pages.join(","));
+        }
+
+        format!("{}\n\n{}\n", rules.join("\n"), updates.join("\n"))
+    }
+}
@@ -0,0 +1,316 @@
+use common::{Dir, Grid, Pos};
+use std::collections::{HashMap, HashSet};
+
+// What a guard does when the tile ahead is obstructed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TurnPolicy {
+    #[default]
+    Right,
+    Left,
+    Reverse,
+}
+
+impl TurnPolicy {
+    pub fn turn(self, dir: Dir) -> Dir {
+        match self {
+            TurnPolicy::Right => dir.turn_right(),
+            TurnPolicy::Left => dir.turn_left(),
+            TurnPolicy::Reverse => dir.reverse(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Guard {
+    pub position: Pos,
+    pub direction: Dir,
+}
+
+// Guards that ended a tick on the same tile, by their index in the list given to `patrol`. Two
+// guards that swapped tiles during the tick walked through each other, `crossed` is then the
+// other tile and `position` the lower of the two.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Collision {
+    pub tick: usize,
+    pub position: Pos,
+    pub crossed: Option<Pos>,
+    pub guards: Vec<usize>,
+}
+
+// Length in ticks of the cycle the guards are stuck in. It's the least common multiple of every
+// guard's own loop, so a handful of guards on long coprime loops can outgrow even 128 bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cycle {
+    Ticks(u128),
+    TooLong,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Patrol {
+    // Ticks until the last guard left the lab, or until the guards started repeating themselves
+    pub ticks: usize,
+    // Tiles any guard stood on
+    pub visited: usize,
+    pub collisions: Vec<Collision>,
+    // None once they all left
+    pub cycle: Option<Cycle>,
+}
+
+// Every guard on the map, in reading order
+pub fn find_guards(map: &Grid<char>) -> Vec<Guard> {
+    map.iter()
+        .filter_map(|(position, &cell)| {
+            Dir::from_arrow(cell).map(|direction| Guard {
+                position,
+                direction,
+            })
+        })
+        .collect()
+}
+
+// The guard turns or takes a step, other guards don't block the way
+fn tick(map: &Grid<char>, policy: TurnPolicy, guard: Option<Guard>) -> Option<Guard> {
+    let Guard {
+        position,
+        direction,
+    } = guard?;
+    let next_position = map.step(position, direction)?;
+    Some(if map[next_position] == '#' {
+        Guard {
+            position,
+            direction: policy.turn(direction),
+        }
+    } else {
+        Guard {
+            position: next_position,
+            direction,
+        }
+    })
+}
+
+// States of one guard tick by tick, the ones from `offset` on repeat every `length` ticks. A
+// guard that left the lab is None, which repeats with length 1.
+struct Track {
+    states: Vec<Option<Guard>>,
+    offset: usize,
+    length: usize,
+}
+
+impl Track {
+    fn new(map: &Grid<char>, policy: TurnPolicy, guard: Guard) -> Self {
+        let mut seen: HashMap<Option<Guard>, usize> = HashMap::new();
+        let mut states = vec![];
+        let mut current = Some(guard);
+        loop {
+            if let Some(&offset) = seen.get(&current) {
+                let length = states.len() - offset;
+                return Track {
+                    states,
+                    offset,
+                    length,
+                };
+            }
+            seen.insert(current, states.len());
+            states.push(current);
+            current = tick(map, policy, current);
+        }
+    }
+
+    fn position(&self, tick: usize) -> Option<Pos> {
+        let index = if tick < self.offset {
+            tick
+        } else {
+            self.offset + (tick - self.offset) % self.length
+        };
+        self.states[index].map(|guard| guard.position)
+    }
+
+    // Tiles the guard goes from and to on the tick before and `tick`
+    fn step(&self, tick: usize) -> Option<(Pos, Pos)> {
+        Some((self.position(tick - 1)?, self.position(tick)?))
+    }
+
+    // Ticks into the cycle, counted from `start + 1`, the guard takes each step
+    fn steps(&self, start: usize) -> HashMap<(Pos, Pos), Vec<usize>> {
+        let mut steps: HashMap<(Pos, Pos), Vec<usize>> = HashMap::new();
+        for phase in 0..self.length {
+            if let Some(step) = self.step(start + 1 + phase) {
+                steps.entry(step).or_default().push(phase);
+            }
+        }
+        steps
+    }
+
+    // Ticks into the cycle, counted from `start`, the guard is on each tile
+    fn phases(&self, start: usize) -> HashMap<Pos, Vec<usize>> {
+        let mut phases: HashMap<Pos, Vec<usize>> = HashMap::new();
+        for phase in 0..self.length {
+            if let Some(position) = self.position(start + phase) {
+                phases.entry(position).or_default().push(phase);
+            }
+        }
+        phases
+    }
+}
+
+fn gcd(a: u128, b: u128) -> u128 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+// Inverse of `a` modulo `m`, the two have to be coprime
+fn inverse(a: i128, m: i128) -> i128 {
+    let (mut old_r, mut r) = (a.rem_euclid(m), m);
+    let (mut old_s, mut s) = (1, 0);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_s, s) = (s, old_s - quotient * s);
+    }
+    old_s.rem_euclid(m)
+}
+
+// Smallest t >= 0 with t = a (mod m) and t = b (mod n), from the chinese remainder theorem
+fn first_common(a: usize, m: usize, b: usize, n: usize) -> Option<usize> {
+    let g = gcd(m as u128, n as u128) as i128;
+    let (a, m, b, n) = (a as i128, m as i128, b as i128, n as i128);
+    if (b - a) % g != 0 {
+        return None;
+    }
+    let n_g = n / g;
+    let k = (b - a) / g * inverse(m / g, n_g) % n_g;
+    Some((a + m * k.rem_euclid(n_g)) as usize)
+}
+
+// First tick the two guards end on each tile they share. Both repeat from `start` on, so a
+// meeting there is one phase of each guard's loop lining up.
+fn meetings(first: &Track, second: &Track) -> HashMap<Pos, usize> {
+    let start = first.offset.max(second.offset);
+    let mut meetings: HashMap<Pos, usize> = HashMap::new();
+    for tick in 0..start {
+        if let (Some(a), Some(b)) = (first.position(tick), second.position(tick)) {
+            if a == b {
+                meetings.entry(a).or_insert(tick);
+            }
+        }
+    }
+
+    let second_phases = second.phases(start);
+    for (position, phases) in first.phases(start) {
+        let Some(others) = second_phases.get(&position) else {
+            continue;
+        };
+        for &phase in &phases {
+            for &other in others {
+                if let Some(tick) = first_common(phase, first.length, other, second.length) {
+                    let earliest = meetings.entry(position).or_insert(start + tick);
+                    *earliest = (*earliest).min(start + tick);
+                }
+            }
+        }
+    }
+    meetings
+}
+
+// First tick the two guards swap each pair of tiles, keyed by the lower tile first. Steps repeat
+// from `start + 1` on, where both ends of a step are in the guards' loops.
+fn crossings(first: &Track, second: &Track) -> HashMap<(Pos, Pos), usize> {
+    let start = first.offset.max(second.offset);
+    let key = |a: Pos, b: Pos| (a.min(b), a.max(b));
+    let mut crossings: HashMap<(Pos, Pos), usize> = HashMap::new();
+    for tick in 1..=start {
+        if let (Some((a, b)), Some(other)) = (first.step(tick), second.step(tick)) {
+            if a != b && other == (b, a) {
+                crossings.entry(key(a, b)).or_insert(tick);
+            }
+        }
+    }
+
+    let second_steps = second.steps(start);
+    for ((a, b), phases) in first.steps(start) {
+        let Some(others) = second_steps.get(&(b, a)).filter(|_| a != b) else {
+            continue;
+        };
+        for &phase in &phases {
+            for &other in others {
+                if let Some(tick) = first_common(phase, first.length, other, second.length) {
+                    let earliest = crossings.entry(key(a, b)).or_insert(start + 1 + tick);
+                    *earliest = (*earliest).min(start + 1 + tick);
+                }
+            }
+        }
+    }
+    crossings
+}
+
+// Moves all guards in lockstep until they left the lab or their combined state repeats. Guards
+// don't block each other, so every guard's own loop is found on its own and the combined cycle
+// is the least common multiple of their lengths. Each pair of guards is reported the first tick
+// it meets on a tile or walks through the other, pairs meeting on the same tick and tiles are
+// grouped.
+pub fn patrol(map: &Grid<char>, guards: &[Guard], policy: TurnPolicy) -> Patrol {
+    let tracks: Vec<Track> = guards
+        .iter()
+        .map(|&guard| Track::new(map, policy, guard))
+        .collect();
+
+    let ticks = tracks.iter().map(|track| track.offset).max().unwrap_or(0);
+    let left = tracks
+        .iter()
+        .all(|track| track.states[track.offset].is_none());
+    let cycle = tracks
+        .iter()
+        .try_fold(1, |cycle: u128, track| {
+            let length = track.length as u128;
+            (cycle / gcd(cycle, length)).checked_mul(length)
+        })
+        .map_or(Cycle::TooLong, Cycle::Ticks);
+
+    let visited: HashSet<Pos> = tracks
+        .iter()
+        .flat_map(|track| track.states.iter().flatten())
+        .map(|guard| guard.position)
+        .collect();
+
+    let mut grouped: HashMap<(usize, Pos, Option<Pos>), Vec<usize>> = HashMap::new();
+    for (i, first) in tracks.iter().enumerate() {
+        for (j, second) in tracks.iter().enumerate().skip(i + 1) {
+            for (position, tick) in meetings(first, second) {
+                grouped
+                    .entry((tick, position, None))
+                    .or_default()
+                    .extend([i, j]);
+            }
+            for ((position, crossed), tick) in crossings(first, second) {
+                grouped
+                    .entry((tick, position, Some(crossed)))
+                    .or_default()
+                    .extend([i, j]);
+            }
+        }
+    }
+    let mut collisions: Vec<Collision> = grouped
+        .into_iter()
+        .map(|((tick, position, crossed), mut guards)| {
+            guards.sort();
+            guards.dedup();
+            Collision {
+                tick,
+                position,
+                crossed,
+                guards,
+            }
+        })
+        .collect();
+    collisions.sort_by_key(|collision| (collision.tick, collision.position, collision.crossed));
+
+    Patrol {
+        ticks,
+        visited: visited.len(),
+        collisions,
+        cycle: (!left).then_some(cycle),
+    }
+}
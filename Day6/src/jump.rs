@@ -0,0 +1,90 @@
+use common::{Dir, Grid, Pos};
+use std::collections::HashSet;
+
+// Slot of an orthogonal direction in a table entry, they're every other variant of `Dir`
+fn slot(dir: Dir) -> usize {
+    dir as usize / 2
+}
+
+// For every cell and direction, the cell the guard stops on in front of the next obstacle, None
+// when the guard walks out of the lab instead. The patrol then moves from turn to turn.
+#[derive(Clone)]
+pub struct JumpTable {
+    stops: Grid<[Option<Pos>; 4]>,
+}
+
+impl JumpTable {
+    pub fn new(map: &Grid<char>) -> Self {
+        let mut stops = map.map(|_| [None; 4]);
+        let forward: Vec<Pos> = map.positions().collect();
+
+        // The cell ahead has to be filled in first, which is an earlier cell going up or left
+        for dir in Dir::ORTHOGONAL {
+            let order: Box<dyn Iterator<Item = &Pos>> = match dir {
+                Dir::Up | Dir::Left => Box::new(forward.iter()),
+                _ => Box::new(forward.iter().rev()),
+            };
+            for &pos in order {
+                stops[pos][slot(dir)] = match map.step(pos, dir) {
+                    Some(ahead) if map[ahead] == '#' => Some(pos),
+                    Some(ahead) => stops[ahead][slot(dir)],
+                    None => None,
+                };
+            }
+        }
+
+        JumpTable { stops }
+    }
+
+    // Cells walking towards `obstruction` now stop right before it, returns the entries replaced
+    fn insert(&mut self, map: &Grid<char>, obstruction: Pos) -> Vec<(Pos, usize, Option<Pos>)> {
+        let mut replaced = vec![];
+        for dir in Dir::ORTHOGONAL {
+            let Some(stop) = map.step(obstruction, dir.reverse()) else {
+                continue;
+            };
+            let mut pos = Some(stop);
+            while let Some(current) = pos.filter(|&pos| map[pos] != '#') {
+                let entry = &mut self.stops[current][slot(dir)];
+                replaced.push((current, slot(dir), *entry));
+                *entry = Some(stop);
+                pos = map.step(current, dir.reverse());
+            }
+        }
+        replaced
+    }
+
+    // Whether the guard loops once `obstruction` is added, the table is left as it was.
+    // `visited` is cleared first, it's only passed in to reuse its allocation.
+    pub fn is_loop(
+        &mut self,
+        map: &Grid<char>,
+        start_direction: Dir,
+        start_position: Pos,
+        obstruction: Pos,
+        visited: &mut HashSet<(Pos, Dir)>,
+    ) -> bool {
+        let replaced = self.insert(map, obstruction);
+        visited.clear();
+        let mut position = start_position;
+        let mut direction = start_direction;
+
+        let looped = loop {
+            if !visited.insert((position, direction)) {
+                break true;
+            }
+            match self.stops[position][slot(direction)] {
+                Some(stop) => {
+                    position = stop;
+                    direction = direction.turn_right();
+                }
+                None => break false,
+            }
+        };
+
+        for (pos, slot, entry) in replaced.into_iter().rev() {
+            self.stops[pos][slot] = entry;
+        }
+        looped
+    }
+}
@@ -0,0 +1,259 @@
+use common::{Answer, Dir, Generator, Grid, Knob, Knobs, ParseError, Pos, Rng, Solution};
+use std::collections::HashSet;
+use std::fmt;
+
+mod guards;
+mod jump;
+mod render;
+
+pub use guards::{find_guards, patrol, Collision, Cycle, Guard, Patrol, TurnPolicy};
+pub use jump::JumpTable;
+pub use render::{loop_cycle, render_patrol};
+
+pub const EXAMPLES: &[&str] = &[include_str!("../example1.txt")];
+
+// Lab map, guard facing and guard position
+type Lab = (Grid<char>, Dir, Pos);
+
+// The guard never leaves a lab whose patrol comes back to this position and direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PatrolLoop {
+    pub position: Pos,
+    pub direction: Dir,
+}
+
+impl fmt::Display for PatrolLoop {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "The guard never leaves the lab, the patrol comes back to {},{} heading {:?}",
+            self.position.row, self.position.col, self.direction
+        )
+    }
+}
+
+impl std::error::Error for PatrolLoop {}
+
+fn solve_part1(
+    map: &Grid<char>,
+    start_direction: Dir,
+    start_position: Pos,
+) -> Result<usize, PatrolLoop> {
+    let path = patrol_path(map, start_direction, start_position)?;
+    Ok(path.len() + 1)
+}
+
+// Cells the guard walks through after leaving the start, in the order they're first reached
+fn patrol_path(
+    map: &Grid<char>,
+    start_direction: Dir,
+    start_position: Pos,
+) -> Result<Vec<Pos>, PatrolLoop> {
+    let mut direction = start_direction;
+    let mut position = start_position;
+
+    let mut visited: HashSet<Pos> = HashSet::new();
+    visited.insert(start_position);
+    let mut states = HashSet::new();
+    let mut path = vec![];
+
+    while let Some(next_position) = map.step(position, direction) {
+        if !states.insert((position, direction)) {
+            return Err(PatrolLoop {
+                position,
+                direction,
+            });
+        }
+        if map[next_position] == '#' {
+            direction = direction.turn_right();
+        } else {
+            position = next_position;
+            if visited.insert(position) {
+                path.push(position);
+            }
+        }
+    }
+
+    Ok(path)
+}
+
+// Walks the patrol one cell at a time, `JumpTable::is_loop` gives the same answer turn by turn
+pub fn is_loop(
+    map: &Grid<char>,
+    start_direction: Dir,
+    start_position: Pos,
+    obstruction: Pos,
+) -> bool {
+    let mut visited = HashSet::new();
+    let mut position = start_position;
+    let mut direction = start_direction;
+
+    while visited.insert((position, direction)) {
+        match map.step(position, direction) {
+            Some(next_position) if map[next_position] == '#' || next_position == obstruction => {
+                direction = direction.turn_right();
+            }
+            Some(next_position) => position = next_position,
+            None => return false,
+        }
+    }
+
+    true
+}
+
+pub fn default_threads() -> usize {
+    std::thread::available_parallelism().map_or(1, |threads| threads.get())
+}
+
+// Positions where a new obstruction traps the guard in a loop, sorted. Only cells on the
+// original patrol can change it, those get split between `threads` scoped threads. Fails when
+// the guard is stuck in a loop without any new obstruction.
+pub fn find_obstructions(
+    map: &Grid<char>,
+    start_direction: Dir,
+    start_position: Pos,
+    threads: usize,
+) -> Result<Vec<Pos>, PatrolLoop> {
+    let candidates = patrol_path(map, start_direction, start_position)?;
+    let table = JumpTable::new(map);
+    let chunk_size = candidates.len().div_ceil(threads.max(1)).max(1);
+
+    let mut obstructions: Vec<Pos> = std::thread::scope(|scope| {
+        let workers: Vec<_> = candidates
+            .chunks(chunk_size)
+            .map(|chunk| {
+                let mut table = table.clone();
+                scope.spawn(move || {
+                    let mut visited = HashSet::new();
+                    chunk
+                        .iter()
+                        .copied()
+                        .filter(|&pos| {
+                            table.is_loop(map, start_direction, start_position, pos, &mut visited)
+                        })
+                        .collect::<Vec<Pos>>()
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().expect("Obstruction search panicked"))
+            .collect()
+    });
+
+    obstructions.sort();
+    Ok(obstructions)
+}
+
+fn solve_part2(
+    map: &Grid<char>,
+    start_direction: Dir,
+    start_position: Pos,
+) -> Result<usize, PatrolLoop> {
+    let obstructions = find_obstructions(map, start_direction, start_position, default_threads())?;
+    Ok(obstructions.len())
+}
+
+fn parse(input: &str) -> Result<Lab, ParseError> {
+    let map = Grid::parse(input, "one of ., #, ^, v, < or >", |c| {
+        ".#^v<>".contains(c).then_some(c)
+    })?;
+
+    // Both parts follow the first guard, `find_guards` lists all of them for `patrol`
+    let guard = find_guards(&map)
+        .first()
+        .map(|guard| (guard.direction, guard.position));
+
+    match guard {
+        Some((start_direction, start_position)) => Ok((map, start_direction, start_position)),
+        None => Err(ParseError::at(
+            input,
+            &input[input.len()..],
+            "a guard (^, v, < or >)",
+        )),
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Lab;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1((map, start_direction, start_position): &Self::Input) -> Answer {
+        solve_part1(map, *start_direction, *start_position).into()
+    }
+
+    fn part2((map, start_direction, start_position): &Self::Input) -> Answer {
+        solve_part2(map, *start_direction, *start_position).into()
+    }
+}
+
+// An obstacle the guard keeps turning at when the patrol never leaves the lab
+fn loop_obstacle(map: &Grid<char>, start_direction: Dir, start_position: Pos) -> Option<Pos> {
+    let mut visited = HashSet::new();
+    let mut position = start_position;
+    let mut direction = start_direction;
+
+    while visited.insert((position, direction)) {
+        let next_position = map.step(position, direction)?;
+        if map[next_position] == '#' {
+            direction = direction.turn_right();
+        } else {
+            position = next_position;
+        }
+    }
+
+    // Back at a known state, so the next obstacle ahead is part of the loop
+    loop {
+        let next_position = map.step(position, direction)?;
+        if map[next_position] == '#' {
+            return Some(next_position);
+        }
+        position = next_position;
+    }
+}
+
+impl Generator for Day6 {
+    const KNOBS: &'static [Knob] = &[
+        Knob {
+            name: "size",
+            default: 130,
+            range: 1..=1000,
+            help: "width and height of the lab",
+        },
+        Knob {
+            name: "obstacles",
+            default: 5,
+            range: 0..=90,
+            help: "percentage of obstructed tiles",
+        },
+    ];
+
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        let size = knobs.get("size");
+        let percent = knobs.get("obstacles") as u64;
+
+        let mut lab = Grid::new(size, size, '.');
+        for pos in lab.positions().collect::<Vec<_>>() {
+            if rng.chance(percent) {
+                lab[pos] = '#';
+            }
+        }
+
+        let guard = Pos::new(rng.range(0..size), rng.range(0..size));
+        let facing = *rng.pick(&Dir::ORTHOGONAL);
+        lab[guard] = facing.to_arrow().unwrap_or('^');
+
+        // The guard has to leave the lab, clear obstacles off the patrol until that happens
+        while let Some(obstacle) = loop_obstacle(&lab, facing, guard) {
+            lab[obstacle] = '.';
+        }
+
+        lab.to_string()
+    }
+}
@@ -0,0 +1,90 @@
+use common::{Dir, Grid, Pos};
+use std::collections::{HashMap, HashSet};
+
+const VERTICAL: u8 = 1;
+const HORIZONTAL: u8 = 2;
+const TURN: u8 = 4;
+
+fn axis(dir: Dir) -> u8 {
+    match dir {
+        Dir::Up | Dir::Down => VERTICAL,
+        _ => HORIZONTAL,
+    }
+}
+
+// The lab with the original patrol drawn over it: `|` and `-` for cells crossed up/down or
+// left/right, `+` for cells crossed both ways or turned on, and `O` for each obstruction
+pub fn render_patrol(
+    map: &Grid<char>,
+    start_direction: Dir,
+    start_position: Pos,
+    obstructions: &[Pos],
+) -> String {
+    let mut marks = map.map(|_| 0u8);
+    let mut position = start_position;
+    let mut direction = start_direction;
+    marks[position] |= axis(direction);
+
+    // `parse` doesn't check that the guard leaves the lab, so drawing stops at a repeated state
+    let mut visited = HashSet::new();
+    while visited.insert((position, direction)) {
+        let Some(next_position) = map.step(position, direction) else {
+            break;
+        };
+        if map[next_position] == '#' {
+            direction = direction.turn_right();
+            marks[position] |= TURN;
+        } else {
+            position = next_position;
+            marks[position] |= axis(direction);
+        }
+    }
+
+    let mut lab = map.clone();
+    for (pos, &mark) in marks.iter() {
+        if lab[pos] != '.' {
+            continue;
+        }
+        lab[pos] = match mark {
+            0 => '.',
+            VERTICAL => '|',
+            HORIZONTAL => '-',
+            _ => '+',
+        };
+    }
+    for &obstruction in obstructions {
+        lab[obstruction] = 'O';
+    }
+
+    lab.to_string()
+}
+
+// The loop the guard gets stuck in once `obstruction` is added, as every cell and direction the
+// guard is in along it, turning in place being a state of its own. It starts at the first state
+// the guard comes back to. None when the guard leaves the lab.
+pub fn loop_cycle(
+    map: &Grid<char>,
+    start_direction: Dir,
+    start_position: Pos,
+    obstruction: Pos,
+) -> Option<Vec<(Pos, Dir)>> {
+    let mut states = vec![];
+    let mut seen: HashMap<(Pos, Dir), usize> = HashMap::new();
+    let mut position = start_position;
+    let mut direction = start_direction;
+
+    loop {
+        if let Some(&first) = seen.get(&(position, direction)) {
+            return Some(states.split_off(first));
+        }
+        seen.insert((position, direction), states.len());
+        states.push((position, direction));
+
+        let next_position = map.step(position, direction)?;
+        if map[next_position] == '#' || next_position == obstruction {
+            direction = direction.turn_right();
+        } else {
+            position = next_position;
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use common::{parse_token, Answer, Generator, Knob, Knobs, ParseError, Rng, Solution};
+use std::collections::HashSet;
+
+pub const EXAMPLES: &[&str] = &[include_str!("../example1.txt")];
+
+fn concat(a: u64, b: u64) -> u64 {
+    let result = a;
+    let mut multiplier = 1;
+    let mut temp = b;
+    while temp > 0 {
+        multiplier *= 10;
+        temp /= 10;
+    }
+    result * multiplier + b
+}
+
+fn can_produce_target(numbers: &[u64], target: u64, include_concat: bool) -> bool {
+    let mut dp: HashSet<u64> = HashSet::new();
+    dp.insert(numbers[0]);
+
+    for &num in &numbers[1..] {
+        let mut next_dp = HashSet::new();
+        for &value in &dp {
+            next_dp.insert(value + num);
+            next_dp.insert(value * num);
+            if include_concat {
+                next_dp.insert(concat(value, num));
+            }
+        }
+        dp = next_dp;
+    }
+
+    dp.contains(&target)
+}
+
+fn parse(input: &str) -> Result<Vec<(u64, Vec<u64>)>, ParseError> {
+    input
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|line| {
+            let (target, numbers) = line
+                .split_once(':')
+                .ok_or_else(|| ParseError::at(input, &line[line.len()..], "':'"))?;
+
+            let target: u64 = parse_token(input, target.trim(), "target number")?;
+            let numbers: Vec<u64> = numbers
+                .split_whitespace()
+                .map(|n| parse_token(input, n, "number"))
+                .collect::<Result<_, _>>()?;
+
+            Ok((target, numbers))
+        })
+        .collect()
+}
+
+fn solve(equations: &[(u64, Vec<u64>)], include_concat: bool) -> u64 {
+    let mut total_calibration_result = 0;
+
+    for (target, numbers) in equations {
+        if can_produce_target(numbers, *target, include_concat) {
+            total_calibration_result += target;
+        }
+    }
+
+    total_calibration_result
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<(u64, Vec<u64>)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve(input, false).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve(input, true).into()
+    }
+}
+
+impl Generator for Day7 {
+    const KNOBS: &'static [Knob] = &[
+        Knob {
+            name: "equations",
+            default: 850,
+            range: 1..=100_000,
+            help: "number of equations",
+        },
+        Knob {
+            name: "max_numbers",
+            default: 12,
+            range: 2..=15,
+            help: "most numbers in a single equation",
+        },
+    ];
+
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        // At most 15 digits per equation keeps every intermediate result below 10^15
+        const MAX_DIGITS: usize = 15;
+        let mut output = String::new();
+
+        for _ in 0..knobs.get("equations") {
+            let count = rng.range(2..knobs.get("max_numbers") + 1);
+            let mut numbers: Vec<u64> = vec![];
+            let mut digits = 0;
+            while numbers.len() < count {
+                let number = match rng.range(0..10) {
+                    0 => 100 + rng.below(900),
+                    1..=3 => 10 + rng.below(90),
+                    _ => 1 + rng.below(9),
+                };
+                let length = number.to_string().len();
+                if digits + length + (count - numbers.len() - 1) > MAX_DIGITS {
+                    numbers.push(1 + rng.below(9));
+                    digits += 1;
+                } else {
+                    numbers.push(number);
+                    digits += length;
+                }
+            }
+
+            let mut target = numbers[0];
+            for &number in &numbers[1..] {
+                target = match rng.range(0..3) {
+                    0 => target + number,
+                    1 => target * number,
+                    _ => concat(target, number),
+                };
+            }
+            // Some equations can't be made true at all
+            if rng.chance(30) {
+                target += 1 + rng.below(10);
+            }
+
+            let numbers: Vec<String> = numbers.iter().map(u64::to_string).collect();
+            output.push_str(&format!("{}: {}\n", target, numbers.join(" ")));
+        }
+
+        output
+    }
+}
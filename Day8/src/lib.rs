@@ -0,0 +1,161 @@
+use common::{Answer, Generator, Grid, Knob, Knobs, ParseError, Pos, Rng, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub const EXAMPLES: &[&str] = &[include_str!("../example1.txt")];
+
+type Antennas = HashMap<char, Vec<Pos>>;
+
+fn delta(from: Pos, to: Pos) -> (isize, isize) {
+    (
+        to.row as isize - from.row as isize,
+        to.col as isize - from.col as isize,
+    )
+}
+
+fn find_antinodes_part1(map: &Grid<char>, antenna_locations: &[Pos]) -> HashSet<Pos> {
+    let mut antinodes: HashSet<Pos> = HashSet::new();
+    for (i, &a) in antenna_locations.iter().enumerate() {
+        for &b in &antenna_locations[i + 1..] {
+            let (d_row, d_col) = delta(a, b);
+
+            // One antinode on each side of the pair, as far out as the antennas are apart
+            antinodes.extend(
+                [a.offset((-d_row, -d_col)), b.offset((d_row, d_col))]
+                    .into_iter()
+                    .flatten()
+                    .filter(|&pos| map.contains(pos)),
+            );
+        }
+    }
+
+    antinodes
+}
+
+fn gcd(a: isize, b: isize) -> isize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn find_antinodes_part2(map: &Grid<char>, antenna_locations: &[Pos]) -> HashSet<Pos> {
+    let mut antinodes: HashSet<Pos> = HashSet::new();
+
+    if antenna_locations.len() > 1 {
+        antinodes.extend(antenna_locations);
+    }
+
+    for (i, &a) in antenna_locations.iter().enumerate() {
+        for &b in &antenna_locations[i + 1..] {
+            let (d_row, d_col) = delta(a, b);
+
+            let gcd = gcd(d_row.abs(), d_col.abs());
+            let step = (d_row / gcd, d_col / gcd);
+
+            for (start, step) in [(a, (-step.0, -step.1)), (b, step)] {
+                let mut pos = Some(start);
+                while let Some(p) = pos.filter(|&p| map.contains(p)) {
+                    antinodes.insert(p);
+                    pos = p.offset(step);
+                }
+            }
+        }
+    }
+
+    antinodes
+}
+
+fn solve_part1(map: &Grid<char>, antenna_locations: &Antennas) -> usize {
+    let mut unique_points: HashSet<Pos> = HashSet::new();
+    for locations in antenna_locations.values() {
+        unique_points.extend(find_antinodes_part1(map, locations));
+    }
+    unique_points.len()
+}
+
+fn solve_part2(map: &Grid<char>, antenna_locations: &Antennas) -> usize {
+    let mut unique_antinodes: HashSet<Pos> = HashSet::new();
+    for locations in antenna_locations.values() {
+        unique_antinodes.extend(find_antinodes_part2(map, locations));
+    }
+    unique_antinodes.len()
+}
+
+fn parse(input: &str) -> Result<(Grid<char>, Antennas), ParseError> {
+    let map = Grid::parse(input, "'.' or an antenna frequency", |c| {
+        (c == '.' || c.is_ascii_alphanumeric()).then_some(c)
+    })?;
+
+    let mut antenna_locations: Antennas = HashMap::new();
+    for (pos, &cell) in map.iter() {
+        if cell.is_ascii_alphanumeric() {
+            antenna_locations.entry(cell).or_default().push(pos);
+        }
+    }
+
+    Ok((map, antenna_locations))
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = (Grid<char>, Antennas);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1((map, antenna_locations): &Self::Input) -> Answer {
+        solve_part1(map, antenna_locations).into()
+    }
+
+    fn part2((map, antenna_locations): &Self::Input) -> Answer {
+        solve_part2(map, antenna_locations).into()
+    }
+}
+
+impl Generator for Day8 {
+    const KNOBS: &'static [Knob] = &[
+        Knob {
+            name: "size",
+            default: 50,
+            range: 1..=1000,
+            help: "width and height of the map",
+        },
+        Knob {
+            name: "frequencies",
+            default: 40,
+            range: 0..=62,
+            help: "distinct antenna frequencies",
+        },
+        Knob {
+            name: "antennas",
+            default: 4,
+            range: 1..=100,
+            help: "antennas per frequency",
+        },
+    ];
+
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        let size = knobs.get("size");
+        let mut frequencies: Vec<char> = ('0'..='9').chain('a'..='z').chain('A'..='Z').collect();
+        rng.shuffle(&mut frequencies);
+        frequencies.truncate(knobs.get("frequencies"));
+
+        let mut map = Grid::new(size, size, '.');
+        for frequency in frequencies {
+            for _ in 0..knobs.get("antennas") {
+                // Antennas never share a tile, give up on crowded maps instead of looping forever
+                let free = (0..100)
+                    .map(|_| Pos::new(rng.range(0..size), rng.range(0..size)))
+                    .find(|&pos| map[pos] == '.');
+                if let Some(pos) = free {
+                    map[pos] = frequency;
+                }
+            }
+        }
+
+        map.to_string()
+    }
+}
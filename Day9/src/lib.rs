@@ -0,0 +1,148 @@
+use common::{Answer, Generator, Knob, Knobs, ParseError, Rng, Solution};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+pub const EXAMPLES: &[&str] = &[include_str!("../example1.txt")];
+
+const FREE_SPACE: i32 = -1;
+
+fn solve_part1(values: &[usize]) -> i64 {
+    let mut expanded_list: Vec<i32> = Vec::with_capacity(values.iter().sum());
+    for i in (0..values.len()).step_by(2) {
+        let file_size = values[i];
+        expanded_list.extend(std::iter::repeat_n(i as i32 / 2, file_size));
+        if i + 1 < values.len() {
+            let free_space = values[i + 1];
+            expanded_list.extend(std::iter::repeat_n(FREE_SPACE, free_space));
+        }
+    }
+
+    // `right` is one past the last block still to move
+    let mut left = 0;
+    let mut right = expanded_list.len();
+
+    while left < right {
+        if expanded_list[left] == FREE_SPACE {
+            if expanded_list[right - 1] == FREE_SPACE {
+                right -= 1;
+            } else {
+                expanded_list[left] = expanded_list[right - 1];
+                expanded_list[right - 1] = FREE_SPACE;
+                left += 1;
+                right -= 1;
+            }
+        } else {
+            left += 1;
+        }
+    }
+
+    expanded_list
+        .iter()
+        .enumerate()
+        .filter(|&(_, &value)| value != FREE_SPACE)
+        .map(|(position, &value)| position as i64 * value as i64)
+        .sum()
+}
+
+// Whole files move to the leftmost free span that fits them. Free spans are kept in one min-heap
+// of start positions per span length, so the leftmost fit is the smallest start among the heaps
+// for lengths of at least the file size.
+fn solve_part2(values: &[usize]) -> i64 {
+    let mut files: Vec<(usize, usize)> = vec![];
+    let mut free_spans: [BinaryHeap<Reverse<usize>>; 10] =
+        std::array::from_fn(|_| BinaryHeap::new());
+
+    let mut position = 0;
+    for (i, &size) in values.iter().enumerate() {
+        if i % 2 == 0 {
+            files.push((position, size));
+        } else if size > 0 {
+            free_spans[size].push(Reverse(position));
+        }
+        position += size;
+    }
+
+    for (start, size) in files.iter_mut().rev() {
+        let leftmost = (*size..free_spans.len())
+            .filter_map(|length| {
+                free_spans[length]
+                    .peek()
+                    .map(|&Reverse(span)| (span, length))
+            })
+            .min();
+
+        // Spans right of the file would move it backwards, the space it leaves is never used
+        // since every file left to move is further left
+        if let Some((span, length)) = leftmost.filter(|&(span, _)| span < *start) {
+            free_spans[length].pop();
+            if length > *size {
+                free_spans[length - *size].push(Reverse(span + *size));
+            }
+            *start = span;
+        }
+    }
+
+    files
+        .iter()
+        .enumerate()
+        .map(|(id, &(start, size))| (start..start + size).sum::<usize>() as i64 * id as i64)
+        .sum()
+}
+
+fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+    let disk_map = input.trim_end();
+    if disk_map.is_empty() {
+        return Err(ParseError::at(input, disk_map, "digit"));
+    }
+    disk_map
+        .char_indices()
+        .map(|(idx, x)| {
+            x.to_digit(10)
+                .map(|x| x as usize)
+                .ok_or_else(|| ParseError::at(input, &disk_map[idx..], "digit"))
+        })
+        .collect()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input).into()
+    }
+}
+
+impl Generator for Day9 {
+    const KNOBS: &'static [Knob] = &[Knob {
+        name: "files",
+        default: 10_000,
+        range: 1..=1_000_000,
+        help: "files on the disk map",
+    }];
+
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        let files = knobs.get("files");
+        let mut disk_map = String::new();
+
+        // File lengths are 1 to 9, the free spans between them 0 to 9
+        for file in 0..files {
+            disk_map.push(char::from(b'1' + rng.below(9) as u8));
+            if file + 1 < files {
+                disk_map.push(char::from(b'0' + rng.below(10) as u8));
+            }
+        }
+
+        disk_map.push('\n');
+        disk_map
+    }
+}
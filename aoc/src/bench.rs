@@ -0,0 +1,118 @@
+use common::{ParseError, Solution};
+use std::fmt::Write;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+pub const PHASES: [&str; 3] = ["parse", "part1", "part2"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+
+        Stats {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Measurement {
+    pub day: u8,
+    pub phase: &'static str,
+    pub runs: usize,
+    pub stats: Stats,
+}
+
+fn sample<T>(runs: usize, mut f: impl FnMut() -> T) -> Stats {
+    let samples = (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+
+    Stats::from_samples(samples)
+}
+
+// Times each phase on its own so a slow parse doesn't hide in the part timings
+pub(crate) fn bench<S: Solution>(input: &str, runs: usize) -> Result<[Stats; 3], ParseError> {
+    let parsed = S::parse(input)?;
+
+    Ok([
+        sample(runs, || S::parse(black_box(input))),
+        sample(runs, || S::part1(black_box(&parsed))),
+        sample(runs, || S::part2(black_box(&parsed))),
+    ])
+}
+
+pub fn to_text(measurements: &[Measurement]) -> String {
+    let mut out = format!(
+        "{:<4} {:<6} {:>6} {:>14} {:>14} {:>14}\n",
+        "day", "phase", "runs", "min", "median", "max"
+    );
+    for m in measurements {
+        let _ = writeln!(
+            out,
+            "{:<4} {:<6} {:>6} {:>14} {:>14} {:>14}",
+            m.day,
+            m.phase,
+            m.runs,
+            format!("{:.2?}", m.stats.min),
+            format!("{:.2?}", m.stats.median),
+            format!("{:.2?}", m.stats.max)
+        );
+    }
+    out
+}
+
+pub fn to_csv(measurements: &[Measurement]) -> String {
+    let mut out = "day,phase,runs,min_ns,median_ns,max_ns\n".to_string();
+    for m in measurements {
+        let _ = writeln!(
+            out,
+            "{},{},{},{},{},{}",
+            m.day,
+            m.phase,
+            m.runs,
+            m.stats.min.as_nanos(),
+            m.stats.median.as_nanos(),
+            m.stats.max.as_nanos()
+        );
+    }
+    out
+}
+
+pub fn to_json(measurements: &[Measurement]) -> String {
+    let records: Vec<String> = measurements
+        .iter()
+        .map(|m| {
+            format!(
+                "  {{\"day\": {}, \"phase\": \"{}\", \"runs\": {}, \"min_ns\": {}, \"median_ns\": {}, \"max_ns\": {}}}",
+                m.day,
+                m.phase,
+                m.runs,
+                m.stats.min.as_nanos(),
+                m.stats.median.as_nanos(),
+                m.stats.max.as_nanos()
+            )
+        })
+        .collect();
+
+    format!("[\n{}\n]\n", records.join(",\n"))
+}
@@ -0,0 +1,511 @@
+use std::path::PathBuf;
+
+use common::Pos;
+use day17::Breakpoint;
+use day6::TurnPolicy;
+
+use crate::input::InputSource;
+
+pub const USAGE: &str = "\
+Usage:
+    aoc run --day <N> [--part <1|2>] [--format <text|json>] [--example <K> | <PATH> | -]
+    aoc run --all [--part <1|2>] [--format <text|json>] [--example <K>]
+    aoc bench (--day <N> | --all) [--runs <R>] [--format <text|csv|json>] [--example <K> | <PATH> | -]
+    aoc generate --day <N> [--seed <S>] [--set <KNOB>=<VALUE>]...
+    aoc export [--format <dot|mermaid>] [--example <K> | <PATH> | -]
+    aoc verify [--samples <N>] [--seed <S>] [--example <K> | <PATH> | -]
+    aoc patrol [--threads <T>] [--render] [--loop <ROW>,<COL>] [--guards [--turn <right|left|reverse>]] [--example <K> | <PATH> | -]
+    aoc debug [--register-a <A>] [--max-steps <N>] [--break <I>]... [--break-out <V>]... [--trace <FILE>] [--example <K> | <PATH> | -]
+
+Without an input argument each day reads DayN/input.txt.
+<PATH> reads the given file, '-' reads stdin and --example <K>
+uses the K-th example embedded from the puzzle text.
+
+run --format json prints one {day, part, answer, elapsed_ns} record per
+answer, elapsed_ns covers solving the part but not parsing the input.
+Parts that fail print the reason on stderr and make run exit with 1.
+
+bench times parse, part1 and part2 separately over R runs (default 10)
+and reports the min, median and max wall time of each phase.
+
+generate prints a random input for the day, the same seed (default 0)
+always gives the same input. --set overrides one of the day's size
+knobs, an unknown knob name lists the available ones.
+
+export prints the day 24 gate circuit as a Graphviz (default) or
+Mermaid graph, gates that break the ripple-carry adder rules are red.
+
+verify runs the day 24 circuit on N random x and y values (default 1000)
+and checks that z == x + y, reporting the lowest wrong bit of z.
+
+patrol counts the day 6 obstructions that trap the guard in a loop,
+split over T threads (default: the number of CPUs). --render draws the
+patrol over the map with |, - and +, marking those obstructions with O.
+--loop lists every cell and facing of the loop an obstruction at ROW,COL
+causes.
+--guards moves every guard on the map in lockstep instead, turning
+right (default), left or around at obstacles, and reports collisions
+and whether the guards leave the lab or keep repeating themselves.
+
+debug single-steps the day 17 program, --register-a overrides the initial
+value of A. Without breakpoints every step is printed, otherwise only the
+steps right after instruction I ran or after out printed V. --trace
+writes every step to FILE. The run stops after N steps (default
+1000000) or as soon as the pointer and registers repeat, in which case
+the repeating cycle is shown.";
+
+const DEFAULT_RUNS: usize = 10;
+const DEFAULT_SAMPLES: usize = 1000;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Run(RunOptions),
+    Bench(BenchOptions),
+    Generate(GenerateOptions),
+    Export(ExportOptions),
+    Verify(VerifyOptions),
+    Patrol(PatrolOptions),
+    Debug(DebugOptions),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunFormat {
+    Text,
+    Json,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct RunOptions {
+    pub days: Vec<u8>,
+    pub part: Option<u8>,
+    pub input: InputSource,
+    pub format: RunFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BenchFormat {
+    Text,
+    Csv,
+    Json,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct BenchOptions {
+    pub days: Vec<u8>,
+    pub input: InputSource,
+    pub runs: usize,
+    pub format: BenchFormat,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct GenerateOptions {
+    pub day: u8,
+    pub seed: u64,
+    pub knobs: Vec<(String, usize)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Dot,
+    Mermaid,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ExportOptions {
+    pub input: InputSource,
+    pub format: ExportFormat,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct VerifyOptions {
+    pub input: InputSource,
+    pub samples: usize,
+    pub seed: u64,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct PatrolOptions {
+    pub input: InputSource,
+    pub threads: Option<usize>,
+    pub render: bool,
+    pub cycle: Option<Pos>,
+    // Set by --guards
+    pub policy: Option<TurnPolicy>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct DebugOptions {
+    pub input: InputSource,
+    pub register_a: Option<u64>,
+    pub max_steps: Option<usize>,
+    pub breakpoints: Vec<Breakpoint>,
+    pub trace: Option<PathBuf>,
+}
+
+fn parse_number<T: std::str::FromStr>(flag: &str, value: Option<&String>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("Missing value for {}", flag))?;
+    value
+        .parse()
+        .map_err(|_| format!("Invalid value for {}: {}", flag, value))
+}
+
+fn parse_day(flag: &str, value: Option<&String>) -> Result<u8, String> {
+    let day: u8 = parse_number(flag, value)?;
+    if !(1..=25).contains(&day) {
+        return Err(format!("Day must be between 1 and 25, got {}", day));
+    }
+    Ok(day)
+}
+
+// Day and input arguments shared by every command
+#[derive(Default)]
+struct Selection {
+    days: Vec<u8>,
+    all: bool,
+    input: InputSource,
+}
+
+impl Selection {
+    // Returns false when `arg` isn't a selection argument
+    fn parse_arg<'a>(
+        &mut self,
+        arg: &str,
+        it: &mut impl Iterator<Item = &'a String>,
+    ) -> Result<bool, String> {
+        match arg {
+            "--day" | "-d" => self.days.push(parse_day(arg, it.next())?),
+            "--all" | "-a" => self.all = true,
+            "--example" | "-e" => {
+                let number: usize = parse_number(arg, it.next())?;
+                if number == 0 {
+                    return Err("Example numbers start at 1".to_string());
+                }
+                self.input = InputSource::Example(number);
+            }
+            _ if arg == "-" || !arg.starts_with('-') => {
+                if self.input != InputSource::Default {
+                    return Err(format!("Unexpected input argument: {}", arg));
+                }
+                self.input = InputSource::from_arg(arg);
+            }
+            _ => return Ok(false),
+        }
+
+        Ok(true)
+    }
+
+    fn finish(self) -> Result<(Vec<u8>, InputSource), String> {
+        let days = if self.all {
+            (1..=25).collect()
+        } else {
+            self.days
+        };
+        if days.is_empty() {
+            return Err("Either --day or --all is required".to_string());
+        }
+
+        if days.len() > 1 && matches!(self.input, InputSource::Path(_) | InputSource::Stdin) {
+            return Err("An input file or stdin can only be used with a single --day".to_string());
+        }
+
+        Ok((days, self.input))
+    }
+}
+
+fn parse_run(args: &[String]) -> Result<RunOptions, String> {
+    let mut selection = Selection::default();
+    let mut part = None;
+    let mut format = RunFormat::Text;
+
+    let mut it = args.iter();
+    while let Some(arg) = it.next() {
+        if selection.parse_arg(arg, &mut it)? {
+            continue;
+        }
+        match arg.as_str() {
+            "--part" | "-p" => {
+                let number: u8 = parse_number(arg, it.next())?;
+                if number != 1 && number != 2 {
+                    return Err(format!("Part must be 1 or 2, got {}", number));
+                }
+                part = Some(number);
+            }
+            "--format" | "-f" => {
+                format = match it.next().map(String::as_str) {
+                    Some("text") => RunFormat::Text,
+                    Some("json") => RunFormat::Json,
+                    Some(other) => return Err(format!("Unknown format: {}", other)),
+                    None => return Err(format!("Missing value for {}", arg)),
+                };
+            }
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        }
+    }
+
+    let (days, input) = selection.finish()?;
+    Ok(RunOptions {
+        days,
+        part,
+        input,
+        format,
+    })
+}
+
+fn parse_bench(args: &[String]) -> Result<BenchOptions, String> {
+    let mut selection = Selection::default();
+    let mut runs = DEFAULT_RUNS;
+    let mut format = BenchFormat::Text;
+
+    let mut it = args.iter();
+    while let Some(arg) = it.next() {
+        if selection.parse_arg(arg, &mut it)? {
+            continue;
+        }
+        match arg.as_str() {
+            "--runs" | "-n" => {
+                runs = parse_number(arg, it.next())?;
+                if runs == 0 {
+                    return Err("At least one run is required".to_string());
+                }
+            }
+            "--format" | "-f" => {
+                format = match it.next().map(String::as_str) {
+                    Some("text") => BenchFormat::Text,
+                    Some("csv") => BenchFormat::Csv,
+                    Some("json") => BenchFormat::Json,
+                    Some(other) => return Err(format!("Unknown format: {}", other)),
+                    None => return Err(format!("Missing value for {}", arg)),
+                };
+            }
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        }
+    }
+
+    let (days, input) = selection.finish()?;
+    Ok(BenchOptions {
+        days,
+        input,
+        runs,
+        format,
+    })
+}
+
+fn parse_generate(args: &[String]) -> Result<GenerateOptions, String> {
+    let mut day = None;
+    let mut seed = 0;
+    let mut knobs = vec![];
+
+    let mut it = args.iter();
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "--day" | "-d" => day = Some(parse_day(arg, it.next())?),
+            "--seed" | "-s" => seed = parse_number(arg, it.next())?,
+            "--set" => {
+                let setting = it
+                    .next()
+                    .ok_or_else(|| format!("Missing value for {}", arg))?;
+                let (name, value) = setting
+                    .split_once('=')
+                    .ok_or_else(|| format!("Expected <KNOB>=<VALUE>, got {}", setting))?;
+                let value = value
+                    .parse()
+                    .map_err(|_| format!("Invalid value for knob {}: {}", name, value))?;
+                knobs.push((name.to_string(), value));
+            }
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        }
+    }
+
+    let day = day.ok_or_else(|| "--day is required".to_string())?;
+    Ok(GenerateOptions { day, seed, knobs })
+}
+
+fn parse_export(args: &[String]) -> Result<ExportOptions, String> {
+    let mut selection = Selection::default();
+    let mut format = ExportFormat::Dot;
+
+    let mut it = args.iter();
+    while let Some(arg) = it.next() {
+        if selection.parse_arg(arg, &mut it)? {
+            continue;
+        }
+        match arg.as_str() {
+            "--format" | "-f" => {
+                format = match it.next().map(String::as_str) {
+                    Some("dot") => ExportFormat::Dot,
+                    Some("mermaid") => ExportFormat::Mermaid,
+                    Some(other) => return Err(format!("Unknown format: {}", other)),
+                    None => return Err(format!("Missing value for {}", arg)),
+                };
+            }
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        }
+    }
+
+    if selection.all || selection.days.iter().any(|&day| day != 24) {
+        return Err("export only supports day 24".to_string());
+    }
+
+    Ok(ExportOptions {
+        input: selection.input,
+        format,
+    })
+}
+
+fn parse_verify(args: &[String]) -> Result<VerifyOptions, String> {
+    let mut selection = Selection::default();
+    let mut samples = DEFAULT_SAMPLES;
+    let mut seed = 0;
+
+    let mut it = args.iter();
+    while let Some(arg) = it.next() {
+        if selection.parse_arg(arg, &mut it)? {
+            continue;
+        }
+        match arg.as_str() {
+            "--samples" | "-n" => {
+                samples = parse_number(arg, it.next())?;
+                if samples == 0 {
+                    return Err("At least one sample is required".to_string());
+                }
+            }
+            "--seed" | "-s" => seed = parse_number(arg, it.next())?,
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        }
+    }
+
+    if selection.all || selection.days.iter().any(|&day| day != 24) {
+        return Err("verify only supports day 24".to_string());
+    }
+
+    Ok(VerifyOptions {
+        input: selection.input,
+        samples,
+        seed,
+    })
+}
+
+fn parse_patrol(args: &[String]) -> Result<PatrolOptions, String> {
+    let mut selection = Selection::default();
+    let mut threads = None;
+    let mut render = false;
+    let mut cycle = None;
+    let mut guards = false;
+    let mut turn = None;
+
+    let mut it = args.iter();
+    while let Some(arg) = it.next() {
+        if selection.parse_arg(arg, &mut it)? {
+            continue;
+        }
+        match arg.as_str() {
+            "--threads" | "-j" => {
+                let count: usize = parse_number(arg, it.next())?;
+                if count == 0 {
+                    return Err("At least one thread is required".to_string());
+                }
+                threads = Some(count);
+            }
+            "--render" | "-r" => render = true,
+            "--loop" | "-l" => {
+                let value = it
+                    .next()
+                    .ok_or_else(|| format!("Missing value for {}", arg))?;
+                let (row, col) = value
+                    .split_once(',')
+                    .ok_or_else(|| format!("Expected <ROW>,<COL> for {}, got {}", arg, value))?;
+                let row = parse_number(arg, Some(&row.to_string()))?;
+                let col = parse_number(arg, Some(&col.to_string()))?;
+                cycle = Some(Pos::new(row, col));
+            }
+            "--guards" | "-g" => guards = true,
+            "--turn" => {
+                turn = match it.next().map(String::as_str) {
+                    Some("right") => Some(TurnPolicy::Right),
+                    Some("left") => Some(TurnPolicy::Left),
+                    Some("reverse") => Some(TurnPolicy::Reverse),
+                    Some(other) => return Err(format!("Unknown turn policy: {}", other)),
+                    None => return Err(format!("Missing value for {}", arg)),
+                };
+            }
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        }
+    }
+
+    if selection.all || selection.days.iter().any(|&day| day != 6) {
+        return Err("patrol only supports day 6".to_string());
+    }
+    if turn.is_some() && !guards {
+        return Err("--turn only applies to --guards".to_string());
+    }
+    if guards && (render || cycle.is_some()) {
+        return Err("--guards can't be combined with --render or --loop".to_string());
+    }
+
+    Ok(PatrolOptions {
+        input: selection.input,
+        threads,
+        render,
+        cycle,
+        policy: guards.then(|| turn.unwrap_or_default()),
+    })
+}
+
+fn parse_debug(args: &[String]) -> Result<DebugOptions, String> {
+    let mut selection = Selection::default();
+    let mut register_a = None;
+    let mut max_steps = None;
+    let mut breakpoints = vec![];
+    let mut trace = None;
+
+    let mut it = args.iter();
+    while let Some(arg) = it.next() {
+        if selection.parse_arg(arg, &mut it)? {
+            continue;
+        }
+        match arg.as_str() {
+            "--register-a" | "-r" => register_a = Some(parse_number(arg, it.next())?),
+            "--max-steps" => max_steps = Some(parse_number(arg, it.next())?),
+            "--break" | "-b" => {
+                breakpoints.push(Breakpoint::Instruction(parse_number(arg, it.next())?))
+            }
+            "--break-out" => breakpoints.push(Breakpoint::Output(parse_number(arg, it.next())?)),
+            "--trace" | "-t" => {
+                let path = it
+                    .next()
+                    .ok_or_else(|| format!("Missing value for {}", arg))?;
+                trace = Some(PathBuf::from(path));
+            }
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        }
+    }
+
+    // The 3-bit computer only exists in day 17, --day 17 is accepted for symmetry with run
+    if selection.all || selection.days.iter().any(|&day| day != 17) {
+        return Err("debug only supports day 17".to_string());
+    }
+
+    Ok(DebugOptions {
+        input: selection.input,
+        register_a,
+        max_steps,
+        breakpoints,
+        trace,
+    })
+}
+
+pub fn parse(args: &[String]) -> Result<Command, String> {
+    match args.split_first() {
+        Some((command, rest)) if command == "run" => parse_run(rest).map(Command::Run),
+        Some((command, rest)) if command == "bench" => parse_bench(rest).map(Command::Bench),
+        Some((command, rest)) if command == "generate" => {
+            parse_generate(rest).map(Command::Generate)
+        }
+        Some((command, rest)) if command == "export" => parse_export(rest).map(Command::Export),
+        Some((command, rest)) if command == "verify" => parse_verify(rest).map(Command::Verify),
+        Some((command, rest)) if command == "patrol" => parse_patrol(rest).map(Command::Patrol),
+        Some((command, rest)) if command == "debug" => parse_debug(rest).map(Command::Debug),
+        Some((command, _)) => Err(format!("Unknown command: {}", command)),
+        None => Err("Missing command".to_string()),
+    }
+}
@@ -0,0 +1,58 @@
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+use crate::Day;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum InputSource {
+    // DayN/input.txt
+    #[default]
+    Default,
+
+    Path(PathBuf),
+    Stdin,
+    // 1-based index into the day's embedded puzzle examples
+    Example(usize),
+}
+
+impl InputSource {
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::Path(PathBuf::from(arg))
+        }
+    }
+
+    pub fn read(&self, day: &Day) -> Result<String, String> {
+        match self {
+            InputSource::Default => {
+                let path = crate::default_input_path(day.number);
+                fs::read_to_string(&path)
+                    .map_err(|err| format!("Failed to read {}: {}", path.display(), err))
+            }
+            InputSource::Path(path) => fs::read_to_string(path)
+                .map_err(|err| format!("Failed to read {}: {}", path.display(), err)),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|err| format!("Failed to read stdin: {}", err))?;
+                Ok(input)
+            }
+            InputSource::Example(number) => number
+                .checked_sub(1)
+                .and_then(|idx| day.examples.get(idx))
+                .map(|example| example.to_string())
+                .ok_or_else(|| {
+                    format!(
+                        "Day {} has no example {} (available: 1..={})",
+                        day.number,
+                        number,
+                        day.examples.len()
+                    )
+                }),
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use common::{Answer, Generator, Knob, Knobs, ParseError, Rng, Solution};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use bench::{Measurement, Stats, PHASES};
+
+pub mod bench;
+pub mod cli;
+pub mod input;
+pub mod report;
+
+// Answer of a single part and the time spent computing it, parsing excluded
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartAnswer {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+pub type Answers = Vec<PartAnswer>;
+
+pub struct Day {
+    pub number: u8,
+    solve: fn(&str, Option<u8>) -> Result<Answers, ParseError>,
+    parse: fn(&str) -> Result<(), ParseError>,
+    bench: fn(&str, usize) -> Result<[Stats; 3], ParseError>,
+    check: fn(&Knobs) -> Result<(), String>,
+    generate: fn(&mut Rng, &Knobs) -> String,
+    // Size parameters of the day's input generator
+    pub knobs: &'static [Knob],
+    // Sample inputs from the puzzle description, compiled into the binary
+    pub examples: &'static [&'static str],
+}
+
+impl Day {
+    pub fn solve(&self, input: &str, part: Option<u8>) -> Result<Answers, ParseError> {
+        let mut answers = (self.solve)(input, part).map_err(|err| err.with_day(self.number))?;
+        for answer in &mut answers {
+            answer.day = self.number;
+        }
+        Ok(answers)
+    }
+
+    // Only parses the input, without solving either part
+    pub fn parse(&self, input: &str) -> Result<(), ParseError> {
+        (self.parse)(input).map_err(|err| err.with_day(self.number))
+    }
+
+    // Runs parse, part1 and part2 `runs` times each
+    pub fn bench(&self, input: &str, runs: usize) -> Result<Vec<Measurement>, ParseError> {
+        let stats = (self.bench)(input, runs).map_err(|err| err.with_day(self.number))?;
+
+        Ok(PHASES
+            .into_iter()
+            .zip(stats)
+            .map(|(phase, stats)| Measurement {
+                day: self.number,
+                phase,
+                runs,
+                stats,
+            })
+            .collect())
+    }
+
+    // Random input in the puzzle's format, the same seed and knobs always give the same input
+    pub fn generate(&self, seed: u64, overrides: &[(String, usize)]) -> Result<String, String> {
+        let knobs = Knobs::new(self.knobs, overrides)
+            .and_then(|knobs| (self.check)(&knobs).map(|_| knobs))
+            .map_err(|err| format!("Day {}: {}", self.number, err))?;
+        Ok((self.generate)(&mut Rng::new(seed), &knobs))
+    }
+}
+
+fn solve_part<I>(part: u8, input: &I, solve: fn(&I) -> Answer) -> PartAnswer {
+    let start = Instant::now();
+    let answer = solve(input);
+
+    // The day is filled in by `Day::solve`
+    PartAnswer {
+        day: 0,
+        part,
+        answer,
+        elapsed: start.elapsed(),
+    }
+}
+
+fn solve<S: Solution>(input: &str, part: Option<u8>) -> Result<Answers, ParseError> {
+    let parsed = S::parse(input)?;
+    let mut answers = vec![];
+
+    if part != Some(2) {
+        answers.push(solve_part(1, &parsed, S::part1));
+    }
+    if part != Some(1) {
+        answers.push(solve_part(2, &parsed, S::part2));
+    }
+
+    Ok(answers)
+}
+
+fn parse<S: Solution>(input: &str) -> Result<(), ParseError> {
+    S::parse(input).map(|_| ())
+}
+
+const fn day<S: Solution + Generator>(number: u8, examples: &'static [&'static str]) -> Day {
+    Day {
+        number,
+        solve: solve::<S>,
+        parse: parse::<S>,
+        bench: bench::bench::<S>,
+        check: S::check,
+        generate: S::generate,
+        knobs: S::KNOBS,
+        examples,
+    }
+}
+
+pub const DAYS: [Day; 25] = [
+    day::<day1::Day1>(1, day1::EXAMPLES),
+    day::<day2::Day2>(2, day2::EXAMPLES),
+    day::<day3::Day3>(3, day3::EXAMPLES),
+    day::<day4::Day4>(4, day4::EXAMPLES),
+    day::<day5::Day5>(5, day5::EXAMPLES),
+    day::<day6::Day6>(6, day6::EXAMPLES),
+    day::<day7::Day7>(7, day7::EXAMPLES),
+    day::<day8::Day8>(8, day8::EXAMPLES),
+    day::<day9::Day9>(9, day9::EXAMPLES),
+    day::<day10::Day10>(10, day10::EXAMPLES),
+    day::<day11::Day11>(11, day11::EXAMPLES),
+    day::<day12::Day12>(12, day12::EXAMPLES),
+    day::<day13::Day13>(13, day13::EXAMPLES),
+    day::<day14::Day14>(14, day14::EXAMPLES),
+    day::<day15::Day15>(15, day15::EXAMPLES),
+    day::<day16::Day16>(16, day16::EXAMPLES),
+    day::<day17::Day17>(17, day17::EXAMPLES),
+    day::<day18::Day18>(18, day18::EXAMPLES),
+    day::<day19::Day19>(19, day19::EXAMPLES),
+    day::<day20::Day20>(20, day20::EXAMPLES),
+    day::<day21::Day21>(21, day21::EXAMPLES),
+    day::<day22::Day22>(22, day22::EXAMPLES),
+    day::<day23::Day23>(23, day23::EXAMPLES),
+    day::<day24::Day24>(24, day24::EXAMPLES),
+    day::<day25::Day25>(25, day25::EXAMPLES),
+];
+
+pub fn find_day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+// Every day keeps its data files next to its sources: DayN/input.txt, DayN/example1.txt, ...
+pub fn day_file_path(number: u8, file_name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("Day{}", number))
+        .join(file_name)
+}
+
+pub fn default_input_path(number: u8) -> PathBuf {
+    day_file_path(number, "input.txt")
+}
@@ -0,0 +1,319 @@
+use std::{env, process};
+
+use aoc::cli::{
+    self, BenchFormat, BenchOptions, Command, DebugOptions, ExportFormat, ExportOptions,
+    GenerateOptions, PatrolOptions, RunFormat, RunOptions, VerifyOptions,
+};
+use aoc::{bench, report};
+use common::{Answer, Grid, Rng, Solution};
+use day17::{Day17, Debugger, VmError};
+use day24::Day24;
+use day6::{Cycle, Day6, TurnPolicy};
+
+fn run(options: &RunOptions) -> Result<(), String> {
+    let mut answers = vec![];
+    let mut failures = vec![];
+
+    for &number in &options.days {
+        let day =
+            aoc::find_day(number).ok_or_else(|| format!("Day {} is not registered", number))?;
+        let input = options.input.read(day)?;
+
+        let solved = day
+            .solve(&input, options.part)
+            .map_err(|err| err.render(&input))?;
+
+        // Failed parts are reported on stderr and make the run fail once every day is done
+        let (failed, answered): (Vec<_>, Vec<_>) = solved
+            .iter()
+            .cloned()
+            .partition(|answer| matches!(answer.answer, Answer::Failed(_)));
+        eprint!("{}", report::to_text(&failed));
+        failures.extend(failed.iter().map(|answer| (answer.day, answer.part)));
+
+        // Text output keeps streaming day by day, slow days shouldn't hold back earlier answers
+        match options.format {
+            RunFormat::Text => print!("{}", report::to_text(&answered)),
+            RunFormat::Json => answers.extend(solved),
+        }
+    }
+
+    if options.format == RunFormat::Json {
+        print!("{}", report::to_json(&answers));
+    }
+
+    match failures.as_slice() {
+        [] => Ok(()),
+        [(day, part)] => Err(format!("Day {} part {} failed", day, part)),
+        _ => Err(format!("{} parts failed", failures.len())),
+    }
+}
+
+fn run_bench(options: &BenchOptions) -> Result<(), String> {
+    let mut measurements = vec![];
+
+    for &number in &options.days {
+        let day =
+            aoc::find_day(number).ok_or_else(|| format!("Day {} is not registered", number))?;
+        let input = options.input.read(day)?;
+
+        measurements.extend(
+            day.bench(&input, options.runs)
+                .map_err(|err| err.render(&input))?,
+        );
+    }
+
+    let report = match options.format {
+        BenchFormat::Text => bench::to_text(&measurements),
+        BenchFormat::Csv => bench::to_csv(&measurements),
+        BenchFormat::Json => bench::to_json(&measurements),
+    };
+    print!("{}", report);
+
+    Ok(())
+}
+
+fn generate(options: &GenerateOptions) -> Result<(), String> {
+    let day = aoc::find_day(options.day)
+        .ok_or_else(|| format!("Day {} is not registered", options.day))?;
+    print!("{}", day.generate(options.seed, &options.knobs)?);
+
+    Ok(())
+}
+
+fn export(options: &ExportOptions) -> Result<(), String> {
+    let day = aoc::find_day(24).ok_or_else(|| "Day 24 is not registered".to_string())?;
+    let input = options.input.read(day)?;
+    let circuit = Day24::parse(&input).map_err(|err| err.with_day(24).render(&input))?;
+
+    match options.format {
+        ExportFormat::Dot => print!("{}", day24::to_dot(&circuit)),
+        ExportFormat::Mermaid => print!("{}", day24::to_mermaid(&circuit)),
+    }
+
+    Ok(())
+}
+
+fn verify(options: &VerifyOptions) -> Result<(), String> {
+    let day = aoc::find_day(24).ok_or_else(|| "Day 24 is not registered".to_string())?;
+    let input = options.input.read(day)?;
+    let circuit = Day24::parse(&input).map_err(|err| err.with_day(24).render(&input))?;
+
+    let mut rng = Rng::new(options.seed);
+    let mismatch =
+        day24::verify_adder(&circuit, options.samples, &mut rng).map_err(|err| err.to_string())?;
+    match mismatch {
+        None => {
+            println!("z == x + y for all {} samples", options.samples);
+            Ok(())
+        }
+        Some(mismatch) => {
+            let mut message = format!(
+                "Bit {} of z is wrong: {} + {} = {}, the circuit gives {}",
+                mismatch.bit,
+                mismatch.x,
+                mismatch.y,
+                mismatch.x + mismatch.y,
+                mismatch.z
+            );
+            if let Some(malformed) = day24::check_adder(&circuit) {
+                message.push_str(&format!("\n{}", malformed));
+            }
+            match day24::find_swaps(&circuit) {
+                Ok(swaps) => {
+                    for swap in swaps {
+                        message.push_str(&format!("\n  {}", swap));
+                    }
+                }
+                Err(err) => message.push_str(&format!("\n{}", err)),
+            }
+            Err(message)
+        }
+    }
+}
+
+fn patrol_guards(map: &Grid<char>, policy: TurnPolicy) -> Result<(), String> {
+    let guards = day6::find_guards(map);
+    let report = day6::patrol(map, &guards, policy);
+
+    match report.cycle {
+        None => println!(
+            "{} guards visited {} tiles, the last one left after {} ticks",
+            guards.len(),
+            report.visited,
+            report.ticks
+        ),
+        Some(Cycle::Ticks(length)) => println!(
+            "{} guards visited {} tiles and repeat every {} ticks from tick {} on",
+            guards.len(),
+            report.visited,
+            length,
+            report.ticks
+        ),
+        Some(Cycle::TooLong) => println!(
+            "{} guards visited {} tiles and repeat from tick {} on, after more ticks than fit in \
+             128 bits",
+            guards.len(),
+            report.visited,
+            report.ticks
+        ),
+    }
+    for collision in &report.collisions {
+        let numbers: Vec<String> = collision
+            .guards
+            .iter()
+            .map(|index| (index + 1).to_string())
+            .collect();
+        let place = match collision.crossed {
+            None => format!("at {},{}", collision.position.row, collision.position.col),
+            Some(crossed) => format!(
+                "walking through each other between {},{} and {},{}",
+                collision.position.row, collision.position.col, crossed.row, crossed.col
+            ),
+        };
+        println!(
+            "  tick {}: guards {} collide {}",
+            collision.tick,
+            numbers.join(", "),
+            place
+        );
+    }
+
+    Ok(())
+}
+
+fn patrol(options: &PatrolOptions) -> Result<(), String> {
+    let day = aoc::find_day(6).ok_or_else(|| "Day 6 is not registered".to_string())?;
+    let input = options.input.read(day)?;
+    let (map, direction, position) =
+        Day6::parse(&input).map_err(|err| err.with_day(6).render(&input))?;
+
+    if let Some(policy) = options.policy {
+        return patrol_guards(&map, policy);
+    }
+
+    let threads = options.threads.unwrap_or_else(day6::default_threads);
+    let obstructions = day6::find_obstructions(&map, direction, position, threads)
+        .map_err(|err| err.to_string())?;
+    if options.render {
+        print!(
+            "{}",
+            day6::render_patrol(&map, direction, position, &obstructions)
+        );
+    }
+    println!(
+        "{} obstructions trap the guard in a loop ({} threads)",
+        obstructions.len(),
+        threads
+    );
+
+    if let Some(obstruction) = options.cycle {
+        if map.get(obstruction) != Some(&'.') {
+            return Err(format!(
+                "{},{} isn't an empty tile of the lab",
+                obstruction.row, obstruction.col
+            ));
+        }
+        let cycle = day6::loop_cycle(&map, direction, position, obstruction).ok_or_else(|| {
+            format!(
+                "An obstruction at {},{} doesn't trap the guard",
+                obstruction.row, obstruction.col
+            )
+        })?;
+        println!(
+            "An obstruction at {},{} traps the guard in a loop of {} steps:",
+            obstruction.row,
+            obstruction.col,
+            cycle.len()
+        );
+        for (pos, dir) in cycle {
+            println!("  {},{} facing {:?}", pos.row, pos.col, dir);
+        }
+    }
+
+    Ok(())
+}
+
+fn debug(options: &DebugOptions) -> Result<(), String> {
+    let day = aoc::find_day(17).ok_or_else(|| "Day 17 is not registered".to_string())?;
+    let input = options.input.read(day)?;
+    let (registers, program) =
+        Day17::parse(&input).map_err(|err| err.with_day(17).render(&input))?;
+
+    let mut debugger = Debugger::new(&program, registers);
+    if let Some(register_a) = options.register_a {
+        debugger.set_register_a(register_a);
+    }
+    if let Some(max_steps) = options.max_steps {
+        debugger.set_step_limit(max_steps);
+    }
+    for &breakpoint in &options.breakpoints {
+        debugger.add_breakpoint(breakpoint);
+    }
+
+    let result = debug_steps(&mut debugger, options.breakpoints.is_empty());
+
+    // The trace leading up to a failure is the interesting part, so it's written either way
+    if let Some(path) = &options.trace {
+        debugger
+            .write_trace(path)
+            .map_err(|err| format!("Failed to write {}: {}", path.display(), err))?;
+    }
+
+    let output: Vec<String> = debugger.output().iter().map(|v| v.to_string()).collect();
+    let summary = format!(
+        "after {} steps, output: {}",
+        debugger.trace().len(),
+        output.join(",")
+    );
+    match result {
+        Ok(()) => {
+            println!("Halted {}", summary);
+            Ok(())
+        }
+        Err(err) => Err(format!("Stopped {}\n{}", summary, err)),
+    }
+}
+
+// Prints every step, or only the steps hitting a breakpoint when there are any
+fn debug_steps(debugger: &mut Debugger, every_step: bool) -> Result<(), VmError> {
+    if every_step {
+        while let Some(step) = debugger.step()? {
+            println!("{}", step);
+        }
+    } else {
+        while let Some(breakpoint) = debugger.resume()? {
+            let step = debugger
+                .trace()
+                .last()
+                .expect("A breakpoint is hit by a step");
+            println!("Breakpoint {}\n{}", breakpoint, step);
+        }
+    }
+
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let result = match cli::parse(&args) {
+        Ok(Command::Run(options)) => run(&options),
+        Ok(Command::Bench(options)) => run_bench(&options),
+        Ok(Command::Generate(options)) => generate(&options),
+        Ok(Command::Export(options)) => export(&options),
+        Ok(Command::Verify(options)) => verify(&options),
+        Ok(Command::Patrol(options)) => patrol(&options),
+        Ok(Command::Debug(options)) => debug(&options),
+
+        Err(err) => {
+            eprintln!("{}\n\n{}", err, cli::USAGE);
+            process::exit(2);
+        }
+    };
+
+    if let Err(err) = result {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
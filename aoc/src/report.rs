@@ -0,0 +1,57 @@
+use common::Answer;
+
+use crate::PartAnswer;
+
+pub fn to_text(answers: &[PartAnswer]) -> String {
+    answers
+        .iter()
+        .map(|a| format!("Day {} part {}: {}\n", a.day, a.part, a.answer))
+        .collect()
+}
+
+fn json_string(text: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+// Numbers stay numbers, text answers become strings and missing or failed answers null
+fn json_answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Int(value) => value.to_string(),
+        Answer::Text(text) => json_string(text),
+        Answer::None | Answer::Failed(_) => "null".to_string(),
+    }
+}
+
+pub fn to_json(answers: &[PartAnswer]) -> String {
+    let records: Vec<String> = answers
+        .iter()
+        .map(|a| {
+            // Failed parts also carry the reason
+            let error = match &a.answer {
+                Answer::Failed(reason) => format!(", \"error\": {}", json_string(reason)),
+                _ => String::new(),
+            };
+            format!(
+                "  {{\"day\": {}, \"part\": {}, \"answer\": {}{}, \"elapsed_ns\": {}}}",
+                a.day,
+                a.part,
+                json_answer(&a.answer),
+                error,
+                a.elapsed.as_nanos()
+            )
+        })
+        .collect();
+
+    format!("[\n{}\n]\n", records.join(",\n"))
+}
@@ -0,0 +1,114 @@
+use std::collections::BTreeMap;
+use std::fs;
+
+use aoc::input::InputSource;
+
+const ANSWERS_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt");
+
+struct Expected {
+    day: u8,
+    part: u8,
+    source: String,
+    answer: String,
+}
+
+fn load_expected() -> Vec<Expected> {
+    let content = fs::read_to_string(ANSWERS_FILE).expect("answers.txt is missing");
+
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|(idx, line)| {
+            let fields: Vec<&str> = line.splitn(4, ' ').collect();
+            let [day, part, source, answer] = fields[..] else {
+                panic!(
+                    "answers.txt:{}: expected `<day> <part> <source> <answer>`",
+                    idx + 1
+                );
+            };
+            Expected {
+                day: day.parse().expect("Invalid day in answers.txt"),
+                part: part.parse().expect("Invalid part in answers.txt"),
+                source: source.to_string(),
+                answer: answer.trim().to_string(),
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn every_day_has_expected_input_answers() {
+    let expected = load_expected();
+
+    let missing: Vec<String> = (1..=25)
+        .flat_map(|day| [(day, 1), (day, 2)])
+        .filter(|&(day, part)| {
+            !expected
+                .iter()
+                .any(|e| e.day == day && e.part == part && e.source == "input")
+        })
+        .map(|(day, part)| format!("day {} part {}", day, part))
+        .collect();
+
+    assert!(
+        missing.is_empty(),
+        "No input answer for: {}",
+        missing.join(", ")
+    );
+}
+
+#[test]
+fn answers_match_expected() {
+    // Group by input file so every file is parsed and solved once
+    let mut by_source: BTreeMap<(u8, String), Vec<Expected>> = BTreeMap::new();
+    for expected in load_expected() {
+        by_source
+            .entry((expected.day, expected.source.clone()))
+            .or_default()
+            .push(expected);
+    }
+
+    let mut drift = vec![];
+
+    for ((number, source), expected) in &by_source {
+        let day = aoc::find_day(*number).expect("Day is not registered");
+        let input_source = match source.strip_prefix("example") {
+            Some(k) => InputSource::Example(k.parse().expect("Invalid example source")),
+            None => InputSource::Default,
+        };
+        let input = input_source
+            .read(day)
+            .unwrap_or_else(|err| panic!("{}", err));
+
+        let part = match expected.as_slice() {
+            [single] => Some(single.part),
+            _ => None,
+        };
+        let answers = day
+            .solve(&input, part)
+            .unwrap_or_else(|err| panic!("{}", err.render(&input)));
+
+        for e in expected {
+            let actual = answers
+                .iter()
+                .find(|a| a.part == e.part)
+                .map(|a| a.answer.to_string())
+                .unwrap_or_default();
+
+            if actual != e.answer {
+                drift.push(format!(
+                    "day {} part {} ({})\n  - {}\n  + {}",
+                    e.day, e.part, e.source, e.answer, actual
+                ));
+            }
+        }
+    }
+
+    assert!(
+        drift.is_empty(),
+        "{} answer(s) drifted from answers.txt:\n{}",
+        drift.len(),
+        drift.join("\n")
+    );
+}
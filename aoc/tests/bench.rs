@@ -0,0 +1,21 @@
+use aoc::bench;
+
+#[test]
+fn bench_reports_ordered_stats_for_every_phase() {
+    let day = aoc::find_day(1).expect("Day is not registered");
+    let measurements = day.bench(day.examples[0], 5).expect("Example is valid");
+
+    let phases: Vec<&str> = measurements.iter().map(|m| m.phase).collect();
+    assert_eq!(phases, bench::PHASES);
+    for m in &measurements {
+        assert_eq!((m.day, m.runs), (1, 5));
+        assert!(m.stats.min <= m.stats.median && m.stats.median <= m.stats.max);
+    }
+
+    let csv = bench::to_csv(&measurements);
+    assert_eq!(csv.lines().count(), 4);
+    assert!(csv.starts_with("day,phase,runs,min_ns,median_ns,max_ns\n1,parse,5,"));
+
+    let json = bench::to_json(&measurements);
+    assert_eq!(json.matches("\"day\": 1,").count(), 3);
+}
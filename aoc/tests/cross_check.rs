@@ -0,0 +1,74 @@
+// Days solved with two different algorithms must agree with each other
+use common::Solution;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fs;
+
+fn read_sources(number: u8) -> Vec<String> {
+    ["input.txt", "example1.txt"]
+        .iter()
+        .map(|file_name| {
+            let path = aoc::day_file_path(number, file_name);
+            fs::read_to_string(&path)
+                .unwrap_or_else(|err| panic!("Failed to read {}: {}", path.display(), err))
+        })
+        .collect()
+}
+
+#[test]
+fn day13_memoized_search_matches_linear_algebra() {
+    for input in read_sources(13) {
+        for (button_a, button_b, goal) in day13::Day13::parse(&input).expect("Invalid day 13 input")
+        {
+            let mut memo = HashMap::new();
+            let searched = day13::solve(0, 0, &button_a, &button_b, &goal, &mut memo);
+            let searched = (searched != isize::MAX).then_some(searched);
+
+            assert_eq!(searched, day13::solve_math(&button_a, &button_b, &goal));
+        }
+    }
+}
+
+#[test]
+fn day11_simulation_matches_stone_counting() {
+    for input in read_sources(11) {
+        let stones: VecDeque<u64> = day11::Day11::parse(&input).expect("Invalid day 11 input");
+
+        for iterations in [0, 1, 6, 25] {
+            let mut cache = HashMap::new();
+            let counted: u64 = stones
+                .iter()
+                .map(|&stone| day11::count_stones(stone, iterations, &mut cache))
+                .sum();
+
+            assert_eq!(
+                day11::solve_part1(stones.clone(), iterations) as u64,
+                counted,
+                "{} iterations",
+                iterations
+            );
+        }
+    }
+}
+
+#[test]
+fn day6_jump_table_matches_walking() {
+    let day = aoc::find_day(6).expect("Day is not registered");
+    let knobs = [("size".to_string(), 25), ("obstacles".to_string(), 15)];
+    let mut inputs = vec![day6::EXAMPLES[0].to_string()];
+    inputs.extend((0..5).map(|seed| day.generate(seed, &knobs).expect("Knobs are valid")));
+
+    for input in inputs {
+        let (map, direction, position) = day6::Day6::parse(&input).expect("Invalid day 6 input");
+        let mut table = day6::JumpTable::new(&map);
+        let mut visited = HashSet::new();
+
+        for obstruction in map.find_all(&'.') {
+            assert_eq!(
+                table.is_loop(&map, direction, position, obstruction, &mut visited),
+                day6::is_loop(&map, direction, position, obstruction),
+                "obstruction at {:?}",
+                obstruction
+            );
+        }
+    }
+}
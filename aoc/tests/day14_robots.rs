@@ -0,0 +1,19 @@
+use common::{Answer, Solution};
+use day14::Day14;
+
+#[test]
+fn robots_that_always_share_a_tile_fail_part2() {
+    // Both robots move together, so every second they stand on the same tile
+    let robots = Day14::parse("p=1,1 v=1,1\np=1,1 v=1,1\n").expect("Input is valid");
+    assert_eq!(Day14::part1(&robots), Answer::Int(0));
+    assert_eq!(
+        Day14::part2(&robots),
+        Answer::Failed(
+            "Robots never all stand on their own tile, their positions repeat every 77 seconds"
+                .to_string()
+        )
+    );
+
+    let robots = Day14::parse("p=1,1 v=1,1\np=1,2 v=1,1\n").expect("Input is valid");
+    assert_eq!(Day14::part2(&robots), Answer::Int(100));
+}
@@ -0,0 +1,52 @@
+use common::Solution;
+use day17::{assemble, disassemble, encode, Day17, EXAMPLES};
+
+fn program_line(input: &str) -> &str {
+    input
+        .lines()
+        .find_map(|line| line.strip_prefix("Program: "))
+        .expect("Input has a program")
+        .trim()
+}
+
+#[test]
+fn disassembled_programs_assemble_back_to_the_same_opcodes() {
+    let day = aoc::find_day(17).expect("Day is not registered");
+    let generated: Vec<String> = (0..10)
+        .map(|seed| day.generate(seed, &[]).expect("Default knobs are valid"))
+        .collect();
+    let inputs = day
+        .examples
+        .iter()
+        .copied()
+        .chain(generated.iter().map(String::as_str))
+        .chain([include_str!("../../Day17/input.txt")]);
+
+    for input in inputs {
+        let (_, program) = Day17::parse(input).expect("Input is valid");
+        let source = disassemble(&program);
+        let assembled = assemble(&source).unwrap_or_else(|err| panic!("{}", err.render(&source)));
+        assert_eq!(encode(&assembled), program_line(input), "\n{}", source);
+    }
+}
+
+#[test]
+fn disassembly_resolves_combo_operands() {
+    let (_, program) = Day17::parse(EXAMPLES[1]).expect("Example is valid");
+    assert_eq!(
+        disassemble(&program),
+        "   0: adv A>>3\n   1: out A%8\n   2: jnz 0\n"
+    );
+
+    let program =
+        assemble("bst A%8\nbxl B^5\ncdv A>>B\nbxc B^C (1)\nout B%8").expect("Source is valid");
+    assert_eq!(encode(&program), "2,4,1,5,7,5,4,1,5,5");
+
+    let source = "0: adv A>>3\n1: out D%8\n";
+    let err = assemble(source).expect_err("D is not a register");
+    assert!(
+        err.render(source).contains("combo operand"),
+        "{}",
+        err.render(source)
+    );
+}
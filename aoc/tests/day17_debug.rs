@@ -0,0 +1,163 @@
+use common::{Answer, Solution};
+use day17::{
+    assemble, run_program, Breakpoint, Day17, Debugger, Registers, VmError, EXAMPLES, MAX_STEPS,
+};
+
+#[test]
+fn stepping_reaches_the_same_output_as_part1() {
+    let (registers, program) = Day17::parse(EXAMPLES[0]).expect("Example is valid");
+    let mut debugger = Debugger::new(&program, registers);
+
+    let first = debugger
+        .step()
+        .expect("Example runs")
+        .expect("Program has instructions")
+        .clone();
+    assert_eq!(first.index, 0);
+    assert_eq!(first.instruction_pointer, 1);
+    assert_eq!(
+        first.to_string(),
+        "   0: adv A>>1     ip=1   A=364 B=0 C=0 out=[]"
+    );
+
+    while debugger.step().expect("Example runs").is_some() {}
+    assert!(debugger.is_halted());
+    assert_eq!(debugger.step(), Ok(None));
+
+    let output: Vec<String> = debugger.output().iter().map(|v| v.to_string()).collect();
+    assert_eq!(output.join(","), "4,6,3,5,6,3,5,2,1,0");
+    assert_eq!(
+        debugger.trace().last().map(|step| step.output.len()),
+        Some(10)
+    );
+}
+
+#[test]
+fn breakpoints_stop_after_matching_steps() {
+    let (registers, program) = Day17::parse(EXAMPLES[1]).expect("Example is valid");
+    let mut debugger = Debugger::new(&program, registers);
+    debugger.set_register_a(2024);
+    debugger.add_breakpoint(Breakpoint::Output(3));
+    debugger.add_breakpoint(Breakpoint::Instruction(0));
+
+    assert_eq!(debugger.resume(), Ok(Some(Breakpoint::Instruction(0))));
+    let registers = Registers { a: 253, b: 0, c: 0 };
+    assert_eq!(
+        debugger.trace().last().map(|step| step.registers),
+        Some(registers)
+    );
+
+    let mut hits = vec![];
+    while let Some(breakpoint) = debugger.resume().expect("Example runs") {
+        hits.push(breakpoint);
+    }
+    assert_eq!(
+        hits.iter()
+            .filter(|&&bp| bp == Breakpoint::Output(3))
+            .count(),
+        1
+    );
+    assert_eq!(hits.len(), 4);
+    assert!(debugger.is_halted());
+
+    let path = std::env::temp_dir().join(format!("day17_trace_{}.txt", std::process::id()));
+    debugger.write_trace(&path).expect("Trace is writable");
+    let trace = std::fs::read_to_string(&path).expect("Trace is readable");
+    std::fs::remove_file(&path).expect("Trace is removable");
+
+    assert_eq!(trace.lines().count(), debugger.trace().len());
+    assert!(trace.ends_with("   2: jnz 0        ip=3   A=0 B=0 C=0 out=[5,7,3,0]\n"));
+}
+
+fn run_until_error(source: &str, a: u64) -> VmError {
+    let program = assemble(source).expect("Source is valid");
+    let mut debugger = Debugger::new(&program, Registers::default());
+    debugger.set_register_a(a);
+    loop {
+        match debugger.step() {
+            Ok(Some(_)) => {}
+            Ok(None) => panic!("{} halted without an error", source),
+            Err(err) => return err,
+        }
+    }
+}
+
+#[test]
+fn bad_programs_are_reported_instead_of_crashing() {
+    assert_eq!(
+        run_until_error("bxl B^1\nout ?%8", 0),
+        VmError::ReservedOperand { index: 1 }
+    );
+    assert_eq!(
+        run_until_error("adv A>>A", 64),
+        VmError::ShiftOverflow {
+            index: 0,
+            shift: 64
+        }
+    );
+    assert_eq!(
+        run_until_error("out A%8\njnz 3", 1),
+        VmError::OddJump {
+            index: 1,
+            address: 3
+        }
+    );
+    assert_eq!(
+        run_until_error("jnz 0", 1),
+        VmError::InfiniteLoop {
+            length: 1,
+            cycle: vec![(0, Registers { a: 1, b: 0, c: 0 })]
+        }
+    );
+
+    // Part 1 fails with the cycle for a program that never halts, instead of hanging
+    let input = "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 3,0\n";
+    let day = aoc::find_day(17).expect("Day is not registered");
+    let answers = day.solve(input, Some(1)).expect("Input is valid");
+    let err = run_until_error("jnz 0", 1);
+    assert_eq!(answers[0].answer, Answer::Failed(err.to_string()));
+}
+
+#[test]
+fn runaway_programs_hit_the_step_budget_or_repeat_a_state() {
+    // Halts after 61 iterations of 2 instructions
+    let program = assemble("adv A>>1\njnz 0").expect("Source is valid");
+    let registers = Registers {
+        a: 1 << 60,
+        ..Registers::default()
+    };
+    assert_eq!(
+        run_program(&program, registers, 121),
+        Err(VmError::StepLimitExceeded { limit: 121 })
+    );
+    assert_eq!(run_program(&program, registers, 122), Ok(vec![]));
+
+    // A never changes while B flips between 0 and 1
+    let program = assemble("bxl B^1\nout B%8\njnz 0").expect("Source is valid");
+    let registers = Registers {
+        a: 5,
+        ..Registers::default()
+    };
+    let err = run_program(&program, registers, MAX_STEPS).expect_err("Program never halts");
+    match &err {
+        VmError::InfiniteLoop { length, cycle } => {
+            assert_eq!(*length, 6);
+            assert_eq!(cycle.len(), 6);
+        }
+        _ => panic!("Unexpected error {:?}", err),
+    }
+    assert!(err.to_string().starts_with(
+        "Program loops forever, this cycle of 6 steps repeats:\n  ip=1 A=5 B=1 C=0\n"
+    ));
+
+    let mut debugger = Debugger::new(&program, registers);
+    debugger.set_step_limit(3);
+    assert_eq!(
+        debugger.resume(),
+        Err(VmError::StepLimitExceeded { limit: 3 })
+    );
+
+    let mut debugger = Debugger::new(&program, registers);
+    assert_eq!(debugger.resume(), Err(err));
+    assert_eq!(debugger.trace().len(), 13);
+}
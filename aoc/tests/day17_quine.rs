@@ -0,0 +1,74 @@
+use common::{Answer, Solution};
+use day17::{assemble, find_quine, loop_shift, Day17, QuineError, EXAMPLES};
+
+fn quine(program: &str) -> Result<u64, QuineError> {
+    let input = format!(
+        "Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+        program
+    );
+    let (_, program) = Day17::parse(&input).expect("Program is valid");
+    find_quine(&program)
+}
+
+// Runs the program with the found A and checks it prints itself
+fn assert_reproduces(program: &str, a: u64) {
+    let input = format!(
+        "Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+        a, program
+    );
+    let input = Day17::parse(&input).expect("Program is valid");
+    assert_eq!(Day17::part1(&input).to_string(), program);
+}
+
+#[test]
+fn loop_shape_is_detected_from_the_program() {
+    let (_, program) = Day17::parse(EXAMPLES[1]).expect("Example is valid");
+    assert_eq!(loop_shift(&program), Some(3));
+
+    let shift = |source: &str| loop_shift(&assemble(source).expect("Source is valid"));
+    assert_eq!(shift("bst A%8\nbxl B^1\nout B%8\nadv A>>2\njnz 0"), Some(2));
+    assert_eq!(
+        shift("cdv A>>A\nbst C%8\nadv A>>1\nout B%8\njnz 0"),
+        Some(1)
+    );
+    // Shift by a register, B read before it's written, two outputs and no trailing jnz 0
+    assert_eq!(shift("bst A%8\nadv A>>B\nout A%8\njnz 0"), None);
+    assert_eq!(shift("bxl B^0\nadv A>>3\nout A%8\njnz 0"), None);
+    assert_eq!(shift("adv A>>3\nout A%8\nout A%8\njnz 0"), None);
+    assert_eq!(shift("adv A>>3\nout A%8\njnz 2"), None);
+}
+
+#[test]
+fn quines_are_found_with_and_without_the_loop_shape() {
+    assert_eq!(quine("0,3,5,4,3,0"), Ok(117440));
+    assert_reproduces("0,3,5,4,3,0", 117440);
+
+    // `bxl 0` reads B before anything writes it, so this goes through the bit by bit search
+    assert_eq!(quine("1,0,0,3,5,4,3,0"), Ok(7516168));
+    assert_reproduces("1,0,0,3,5,4,3,0", 7516168);
+}
+
+#[test]
+fn missing_quines_are_reported() {
+    // Prints A one octal digit at a time, which can't end in the 0 of `jnz 0`
+    let err = quine("2,4,5,4,0,3,3,0").expect_err("Last digit of A isn't 0");
+    assert_eq!(err, QuineError::NoSolution { shift: 3 });
+    assert!(err
+        .to_string()
+        .starts_with("No value of A makes the program print itself"));
+
+    // Part 2 fails with the reason instead of a blank answer
+    let input = "Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: 2,4,5,4,0,3,3,0\n";
+    let input = Day17::parse(input).expect("Program is valid");
+    assert_eq!(Day17::part2(&input), Answer::Failed(err.to_string()));
+
+    let err = quine("5,5,5,5").expect_err("B is always 0");
+    assert_eq!(err, QuineError::Unsatisfiable);
+    assert!(err
+        .to_string()
+        .starts_with("No value of A makes the program print itself (searched bit by bit"));
+
+    // Loop forever for every A but 0, the second one with `jnz 4` jumping to itself
+    assert_eq!(quine("3,0"), Err(QuineError::Unsatisfiable));
+    assert_eq!(quine("0,1,5,4,3,4"), Err(QuineError::Unsatisfiable));
+}
@@ -0,0 +1,173 @@
+use common::{Answer, Rng, Solution};
+use day24::{
+    check_adder, find_swaps, verify_adder, AdderPart, Day24, Malformed, Unrepairable, EXAMPLES,
+};
+
+const TWO_BIT_ADDER: &str = "x00: 0\nx01: 0\ny00: 0\ny01: 0\n\n\
+                             x00 XOR y00 -> z00\n\
+                             x00 AND y00 -> c01\n\
+                             x01 XOR y01 -> h01\n\
+                             h01 XOR c01 -> z01\n\
+                             x01 AND y01 -> d01\n\
+                             h01 AND c01 -> r01\n\
+                             d01 OR r01 -> z02\n";
+
+fn malformed(from: &str, to: &str) -> Option<Malformed> {
+    let circuit = Day24::parse(&TWO_BIT_ADDER.replace(from, to)).expect("Input is valid");
+    check_adder(&circuit)
+}
+
+#[test]
+fn inverted_gates_are_evaluated() {
+    let input = "x00: 1\ny00: 0\n\n\
+                 x00 NAND y00 -> z00\n\
+                 x00 NOR y00 -> z01\n\
+                 x00 XNOR y00 -> z02\n\
+                 NOT y00 -> z03\n\
+                 NOT z01 -> z04\n";
+    let circuit = Day24::parse(input).expect("Input is valid");
+    assert_eq!(Day24::part1(&circuit).to_string(), "25");
+
+    match Day24::parse("x00: 1\n\nx00 NOTAND x00 -> z00\n") {
+        Err(err) => assert!(err.to_string().contains("AND, OR, XOR, NAND, NOR or XNOR")),
+        Ok(_) => panic!("Gate is unknown"),
+    }
+}
+
+#[test]
+fn adder_check_reports_the_malformed_stage() {
+    let circuit = Day24::parse(include_str!("../../Day24/input.txt")).expect("Input is valid");
+    let found = check_adder(&circuit).expect("Input has swaps");
+    assert_eq!(
+        (found.bit, found.part, found.to_string()),
+        (
+            18,
+            AdderPart::Sum,
+            "The sum of z18 is malformed".to_string()
+        )
+    );
+
+    assert_eq!(malformed("", ""), None);
+    let cases = [
+        (
+            "x00 XOR y00 -> z00",
+            "x00 OR y00 -> z00",
+            0,
+            AdderPart::HalfAdder,
+        ),
+        (
+            "x01 XOR y01 -> h01",
+            "x01 XNOR y01 -> h01",
+            1,
+            AdderPart::HalfAdder,
+        ),
+        (
+            "h01 XOR c01 -> z01",
+            "h01 AND c01 -> z01",
+            1,
+            AdderPart::Sum,
+        ),
+        (
+            "x00 AND y00 -> c01",
+            "x00 OR y00 -> c01",
+            1,
+            AdderPart::Carry,
+        ),
+        (
+            "h01 AND c01 -> r01",
+            "h01 OR c01 -> r01",
+            2,
+            AdderPart::Recarry,
+        ),
+        (
+            "d01 OR r01 -> z02",
+            "d01 XOR r01 -> z02",
+            2,
+            AdderPart::Carry,
+        ),
+    ];
+    for (from, to, bit, part) in cases {
+        assert_eq!(malformed(from, to), Some(Malformed { bit, part }), "{}", to);
+    }
+
+    let day = aoc::find_day(24).expect("Day is not registered");
+    for bits in [9, 20, 64] {
+        let knobs = [("bits".to_string(), bits), ("swaps".to_string(), 0)];
+        let input = day.generate(5, &knobs).expect("Knobs are valid");
+        let circuit = Day24::parse(&input).expect("Generated input is valid");
+        assert_eq!(check_adder(&circuit), None, "{} bits", bits);
+    }
+}
+
+// Swaps the gates driving each pair of wires back by renaming their outputs
+fn unswap(input: &str, swaps: &[(String, String)]) -> String {
+    let mut input = input.to_string();
+    for (a, b) in swaps {
+        input = input
+            .replace(&format!("-> {}\n", a), "-> @\n")
+            .replace(&format!("-> {}\n", b), &format!("-> {}\n", a))
+            .replace("-> @\n", &format!("-> {}\n", b));
+    }
+    input
+}
+
+#[test]
+fn swaps_are_found_and_explained() {
+    let circuit = Day24::parse(include_str!("../../Day24/input.txt")).expect("Input is valid");
+    let swaps = find_swaps(&circuit).expect("Input can be repaired");
+    let explained: Vec<String> = swaps.iter().map(|swap| swap.to_string()).collect();
+    assert_eq!(
+        explained,
+        [
+            "hmt <-> z18 fixes the sum of z18",
+            "bfq <-> z27 fixes the sum of z27",
+            "hkh <-> z31 fixes the sum of z31",
+            "bng <-> fjp fixes the half adder of z39",
+        ]
+    );
+
+    let day = aoc::find_day(24).expect("Day is not registered");
+    for (seed, bits, count) in [(1, 9, 1), (2, 45, 4), (3, 64, 10), (4, 99, 22)] {
+        let knobs = [("bits".to_string(), bits), ("swaps".to_string(), count)];
+        let input = day.generate(seed, &knobs).expect("Knobs are valid");
+        let circuit = Day24::parse(&input).expect("Generated input is valid");
+        let swaps = find_swaps(&circuit).expect("Generated input can be repaired");
+        assert_eq!(swaps.len(), count, "seed {}", seed);
+
+        let wires: Vec<(String, String)> = swaps.into_iter().map(|swap| swap.wires).collect();
+        let repaired = Day24::parse(&unswap(&input, &wires)).expect("Repaired input is valid");
+        assert_eq!(check_adder(&repaired), None);
+        assert_eq!(verify_adder(&repaired, 50, &mut Rng::new(seed)), Ok(None));
+    }
+}
+
+#[test]
+fn single_output_circuit_isnt_an_adder() {
+    let circuit = Day24::parse("x00: 1\ny00: 0\n\nx00 OR y00 -> z00\n").expect("Input is valid");
+    assert_eq!(Day24::part1(&circuit).to_string(), "1");
+    assert_eq!(
+        Day24::part2(&circuit),
+        Answer::Failed("No swap near z00 fixes its half adder".to_string())
+    );
+    assert_eq!(
+        check_adder(&circuit),
+        Some(Malformed {
+            bit: 0,
+            part: AdderPart::HalfAdder,
+        })
+    );
+    let malformed = check_adder(&circuit).expect("Circuit is malformed");
+    assert_eq!(find_swaps(&circuit), Err(Unrepairable { malformed }));
+}
+
+#[test]
+fn examples_that_arent_adders_fail_part2() {
+    // Both examples only show how gates evaluate, z00 isn't even the XOR of x00 and y00
+    for example in EXAMPLES {
+        let circuit = Day24::parse(example).expect("Example is valid");
+        let malformed = check_adder(&circuit).expect("Example isn't an adder");
+        let err = find_swaps(&circuit).expect_err("Example can't be repaired");
+        assert_eq!(err, Unrepairable { malformed });
+        assert_eq!(Day24::part2(&circuit), Answer::Failed(err.to_string()));
+    }
+}
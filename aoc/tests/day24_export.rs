@@ -0,0 +1,80 @@
+use common::Solution;
+use day24::{find_swaps, to_dot, to_mermaid, Day24};
+
+fn red_nodes(dot: &str) -> Vec<&str> {
+    dot.lines()
+        .filter(|line| line.contains("penwidth=3"))
+        .filter_map(|line| line.trim().split('"').nth(1))
+        .collect()
+}
+
+#[test]
+fn export_highlights_the_swapped_wires() {
+    let circuit = Day24::parse(include_str!("../../Day24/input.txt")).expect("Input is valid");
+
+    let dot = to_dot(&circuit);
+    assert!(dot.starts_with("digraph circuit {\n    rankdir=LR;\n"));
+    assert!(dot.contains("        rank=source;\n        \"x00\" [shape=box];\n"));
+    assert!(dot.contains("        \"z45\" [label=\"z45\\nOR\", fillcolor=\"#a1d99b\"];\n"));
+    assert_eq!(red_nodes(&dot).join(","), "bfq,bng,fjp,hkh,hmt,z18,z27,z31");
+    assert!(dot.contains("tooltip=\"hmt <-> z18 fixes the sum of z18\""));
+
+    let mermaid = to_mermaid(&circuit);
+    assert!(mermaid.starts_with("flowchart LR\n    subgraph inputs\n        w_x00[\"x00\"]\n"));
+    assert!(
+        mermaid.contains("\n    class w_bfq,w_bng,w_fjp,w_hkh,w_hmt,w_z18,w_z27,w_z31 flagged\n")
+    );
+    assert_eq!(
+        mermaid
+            .lines()
+            .filter(|line| line.contains(" --> "))
+            .count(),
+        dot.lines().filter(|line| line.contains(" -> ")).count()
+    );
+}
+
+#[test]
+fn correct_adders_have_nothing_highlighted() {
+    let day = aoc::find_day(24).expect("Day is not registered");
+    let knobs = [("bits".to_string(), 12), ("swaps".to_string(), 0)];
+    for seed in 0..5 {
+        let input = day.generate(seed, &knobs).expect("Knobs are valid");
+        let circuit = Day24::parse(&input).expect("Generated input is valid");
+
+        assert!(red_nodes(&to_dot(&circuit)).is_empty(), "{}", input);
+        assert!(!to_mermaid(&circuit).contains("flagged"));
+    }
+}
+
+#[test]
+fn stages_no_swap_repairs_are_highlighted() {
+    // A two bit adder with the carry out of bit 0 as a NAND, no swap turns that into an AND
+    let input = "x00: 1\nx01: 0\ny00: 1\ny01: 1\n\n\
+                 x00 XOR y00 -> z00\nx00 NAND y00 -> cry\nx01 XOR y01 -> hlf\n\
+                 hlf XOR cry -> z01\nx01 AND y01 -> dir\nhlf AND cry -> rec\n\
+                 dir OR rec -> z02\n";
+    let circuit = Day24::parse(input).expect("Circuit is valid");
+
+    let dot = to_dot(&circuit);
+    assert_eq!(red_nodes(&dot), ["cry"]);
+    assert!(dot.contains("tooltip=\"No swap near z01 fixes its carry\""));
+    assert!(to_mermaid(&circuit).contains("\n    class w_cry flagged\n"));
+}
+
+#[test]
+fn highlighted_wires_match_the_swaps_found() {
+    let day = aoc::find_day(24).expect("Day is not registered");
+    let knobs = [("bits".to_string(), 20), ("swaps".to_string(), 3)];
+    for seed in 0..5 {
+        let input = day.generate(seed, &knobs).expect("Knobs are valid");
+        let circuit = Day24::parse(&input).expect("Generated input is valid");
+
+        let mut wires: Vec<String> = find_swaps(&circuit)
+            .expect("Generated input can be repaired")
+            .into_iter()
+            .flat_map(|swap| [swap.wires.0, swap.wires.1])
+            .collect();
+        wires.sort();
+        assert_eq!(red_nodes(&to_dot(&circuit)), wires, "seed {}", seed);
+    }
+}
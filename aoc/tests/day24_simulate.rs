@@ -0,0 +1,109 @@
+use common::{Answer, Rng, Solution};
+use day24::{input_bits, simulate, verify_adder, CircuitError, Day24};
+
+fn input_number(input: &str, prefix: char) -> u128 {
+    input
+        .lines()
+        .filter_map(|line| line.strip_prefix(prefix))
+        .filter_map(|line| line.split_once(": "))
+        .map(|(bit, value)| (bit.parse::<u32>().unwrap(), value.parse::<u128>().unwrap()))
+        .fold(0, |number, (bit, value)| number | value << bit)
+}
+
+#[test]
+fn simulating_the_input_values_gives_part1() {
+    let input = include_str!("../../Day24/input.txt");
+    let circuit = Day24::parse(input).expect("Input is valid");
+    assert_eq!(input_bits(&circuit), 45);
+
+    let z = simulate(&circuit, input_number(input, 'x'), input_number(input, 'y'))
+        .expect("Input has no loops");
+    assert_eq!(z.to_string(), Day24::part1(&circuit).to_string());
+}
+
+#[test]
+fn verification_finds_the_lowest_wrong_bit() {
+    let circuit = Day24::parse(include_str!("../../Day24/input.txt")).expect("Input is valid");
+    let mismatch = verify_adder(&circuit, 200, &mut Rng::new(1))
+        .expect("Input has no loops")
+        .expect("Input has swaps");
+    assert_eq!(mismatch.bit, 18);
+    assert_eq!(simulate(&circuit, mismatch.x, mismatch.y), Ok(mismatch.z));
+    assert_ne!(mismatch.z, mismatch.x + mismatch.y);
+
+    let day = aoc::find_day(24).expect("Day is not registered");
+    for bits in [9, 20, 99] {
+        let knobs = [("bits".to_string(), bits), ("swaps".to_string(), 0)];
+        let input = day.generate(3, &knobs).expect("Knobs are valid");
+        let circuit = Day24::parse(&input).expect("Generated input is valid");
+
+        let top = (1u128 << bits) - 1;
+        assert_eq!(simulate(&circuit, top, 1), Ok(top + 1));
+        assert_eq!(
+            verify_adder(&circuit, 50, &mut Rng::new(bits as u64)),
+            Ok(None)
+        );
+    }
+}
+
+fn circuit_error(gates: &str) -> CircuitError {
+    let input = format!("x00: 1\ny00: 0\n\n{}", gates);
+    let circuit = Day24::parse(&input).expect("Input is valid");
+    let err = simulate(&circuit, 0, 1).expect_err("Circuit can't be evaluated");
+    assert_eq!(Day24::part1(&circuit), Answer::Failed(err.to_string()));
+    err
+}
+
+#[test]
+fn loops_and_undefined_wires_are_reported() {
+    let err = circuit_error(
+        "x00 AND bbb -> aaa\naaa OR y00 -> ccc\nccc XOR x00 -> bbb\nbbb XOR y00 -> z00\n",
+    );
+    assert_eq!(
+        err,
+        CircuitError::Cycle {
+            wires: ["aaa", "ccc", "bbb"].map(str::to_string).to_vec()
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "Gates form a loop: aaa -> ccc -> bbb -> aaa"
+    );
+
+    let err = circuit_error("x00 AND qqq -> z00\n");
+    assert_eq!(
+        err,
+        CircuitError::UndefinedWire {
+            wire: "qqq".to_string(),
+            gate: "z00".to_string()
+        }
+    );
+}
+
+#[test]
+fn numbers_wider_than_127_bits_are_reported() {
+    let gates = |count: u32| -> String {
+        (0..count)
+            .map(|bit| format!("x00 OR y00 -> z{:02}\n", bit))
+            .collect()
+    };
+    let input = format!("x00: 1\ny00: 0\n\n{}", gates(127));
+    let circuit = Day24::parse(&input).expect("Input is valid");
+    assert_eq!(Day24::part1(&circuit), Answer::Int(i128::MAX));
+
+    assert_eq!(simulate(&circuit, 1, 0), Ok(u128::MAX >> 1));
+
+    let input = format!("x00: 1\ny00: 0\n\n{}", gates(128));
+    let circuit = Day24::parse(&input).expect("Input is valid");
+    let err = CircuitError::TooWide { bits: 128 };
+    assert_eq!(Day24::part1(&circuit), Answer::Failed(err.to_string()));
+    assert_eq!(simulate(&circuit, 1, 0), Err(err.clone()));
+    assert_eq!(verify_adder(&circuit, 10, &mut Rng::new(0)), Err(err));
+
+    // Too many x wires is caught before they're packed into x
+    let wires: String = (0..128).map(|bit| format!("x{:02}: 0\n", bit)).collect();
+    let input = format!("{}y00: 0\n\nx00 OR y00 -> z00\n", wires);
+    let circuit = Day24::parse(&input).expect("Input is valid");
+    let err = CircuitError::TooWide { bits: 128 };
+    assert_eq!(verify_adder(&circuit, 10, &mut Rng::new(0)), Err(err));
+}
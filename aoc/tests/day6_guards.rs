@@ -0,0 +1,225 @@
+use common::{Grid, Pos, Rng, Solution};
+use day6::{find_guards, patrol, Collision, Cycle, Day6, Guard, TurnPolicy};
+use std::collections::HashSet;
+
+fn lab(rows: &[&str]) -> Grid<char> {
+    let input = format!("{}\n", rows.join("\n"));
+    Day6::parse(&input).expect("Lab is valid").0
+}
+
+#[test]
+fn single_guard_patrol_matches_part1() {
+    let input = include_str!("../../Day6/input.txt");
+    let lab = Day6::parse(input).expect("Input is valid");
+    let report = patrol(&lab.0, &find_guards(&lab.0), TurnPolicy::Right);
+    assert_eq!(report.cycle, None);
+    assert_eq!(report.visited.to_string(), Day6::part1(&lab).to_string());
+}
+
+#[test]
+fn guards_meeting_on_a_tile_collide() {
+    let map = lab(&["..#...", ".>...<", "......"]);
+    let report = patrol(&map, &find_guards(&map), TurnPolicy::Right);
+    assert_eq!(
+        report.collisions,
+        [Collision {
+            tick: 2,
+            position: Pos::new(1, 3),
+            crossed: None,
+            guards: vec![0, 1],
+        }]
+    );
+    assert_eq!((report.ticks, report.visited, report.cycle), (6, 6, None));
+
+    // Facing each other on neighbouring tiles, the guards swap places without ever sharing one
+    let map = lab(&[".><."]);
+    let report = patrol(&map, &find_guards(&map), TurnPolicy::Right);
+    assert_eq!(
+        report.collisions,
+        [Collision {
+            tick: 1,
+            position: Pos::new(0, 1),
+            crossed: Some(Pos::new(0, 2)),
+            guards: vec![0, 1],
+        }]
+    );
+}
+
+#[test]
+fn combined_cycle_covers_every_guard() {
+    let map = lab(&[".#...#...", ".^.#.^..#", "#...#....", "..#....#."]);
+    let guards = find_guards(&map);
+    assert_eq!(
+        patrol(&map, &guards[..1], TurnPolicy::Right).cycle,
+        Some(Cycle::Ticks(8))
+    );
+    assert_eq!(
+        patrol(&map, &guards[1..], TurnPolicy::Right).cycle,
+        Some(Cycle::Ticks(10))
+    );
+
+    let report = patrol(&map, &guards, TurnPolicy::Right);
+    assert_eq!(
+        (report.ticks, report.visited, report.cycle),
+        (0, 10, Some(Cycle::Ticks(40)))
+    );
+    assert!(report.collisions.is_empty());
+
+    // Turning left at the first obstacle sends the first guard out of the left edge
+    let report = patrol(&map, &guards[..1], TurnPolicy::Left);
+    assert_eq!((report.ticks, report.cycle), (3, None));
+
+    let map = lab(&[".#.>.#"]);
+    let report = patrol(&map, &find_guards(&map), TurnPolicy::Reverse);
+    assert_eq!((report.visited, report.cycle), (3, Some(Cycle::Ticks(6))));
+}
+
+// Rectangular loops side by side, a guard on a w x h loop comes back every 2 * (w + h) ticks
+fn boxes(widths: &[usize], height: usize) -> Grid<char> {
+    let total = widths.iter().map(|width| width + 1).sum::<usize>() + 1;
+    let mut map = Grid::new(total, height + 2, '.');
+    let mut left = 1;
+    for &width in widths {
+        map[Pos::new(0, left)] = '#';
+        map[Pos::new(1, left + width)] = '#';
+        map[Pos::new(height + 1, left + width - 1)] = '#';
+        map[Pos::new(height, left - 1)] = '#';
+        map[Pos::new(1, left)] = '^';
+        left += width + 1;
+    }
+    map
+}
+
+#[test]
+fn coprime_loops_are_combined_without_replaying_them() {
+    let map = boxes(&[51, 53, 57, 59], 50);
+    let guards = find_guards(&map);
+    assert_eq!(guards.len(), 4);
+
+    let report = patrol(&map, &guards, TurnPolicy::Right);
+    assert_eq!(report.cycle, Some(Cycle::Ticks(202 * 206 * 214 * 218 / 8)));
+    assert_eq!(report.ticks, 0);
+    assert!(report.collisions.is_empty());
+}
+
+#[test]
+fn cycles_past_128_bits_are_too_long() {
+    // Half-perimeters are the primes after 500, twice the product of the first 14 needs 129 bits
+    let widths = [
+        3, 9, 21, 23, 41, 47, 57, 63, 69, 71, 77, 87, 93, 99, 101, 107,
+    ];
+    let map = boxes(&widths, 500);
+    let guards = find_guards(&map);
+    assert_eq!(guards.len(), 16);
+
+    let report = patrol(&map, &guards, TurnPolicy::Right);
+    assert_eq!(report.cycle, Some(Cycle::TooLong));
+    assert_eq!(
+        patrol(&map, &guards[..14], TurnPolicy::Right).cycle,
+        Some(Cycle::TooLong)
+    );
+    let primes = widths[..13].iter().map(|&width| width as u128 + 500);
+    assert_eq!(
+        patrol(&map, &guards[..13], TurnPolicy::Right).cycle,
+        Some(Cycle::Ticks(2 * primes.product::<u128>()))
+    );
+}
+
+// Replays every tick, reporting each pair of guards the first time it meets on a tile or swaps
+// the same two tiles
+fn replayed_collisions(map: &Grid<char>, guards: &[Guard], ticks: usize) -> Vec<Collision> {
+    let mut current: Vec<Option<Guard>> = guards.iter().copied().map(Some).collect();
+    let mut seen = HashSet::new();
+    let mut collisions: Vec<Collision> = vec![];
+    for tick in 0..=ticks {
+        let previous = current.clone();
+        if tick > 0 {
+            for guard in current.iter_mut() {
+                *guard = guard.and_then(|guard| {
+                    let ahead = map.step(guard.position, guard.direction)?;
+                    Some(if map[ahead] == '#' {
+                        Guard {
+                            direction: guard.direction.turn_right(),
+                            ..guard
+                        }
+                    } else {
+                        Guard {
+                            position: ahead,
+                            ..guard
+                        }
+                    })
+                });
+            }
+        }
+
+        for i in 0..current.len() {
+            for j in i + 1..current.len() {
+                let (Some(a), Some(b)) = (current[i], current[j]) else {
+                    continue;
+                };
+                let (position, crossed) = if a.position == b.position {
+                    (a.position, None)
+                } else if tick > 0
+                    && previous[i].map(|guard| guard.position) == Some(b.position)
+                    && previous[j].map(|guard| guard.position) == Some(a.position)
+                {
+                    let low = a.position.min(b.position);
+                    (low, Some(a.position.max(b.position)))
+                } else {
+                    continue;
+                };
+                if !seen.insert((i, j, position, crossed)) {
+                    continue;
+                }
+                match collisions.iter_mut().find(|found| {
+                    found.tick == tick && found.position == position && found.crossed == crossed
+                }) {
+                    Some(found) => found.guards.extend([i, j]),
+                    None => collisions.push(Collision {
+                        tick,
+                        position,
+                        crossed,
+                        guards: vec![i, j],
+                    }),
+                }
+            }
+        }
+    }
+
+    for collision in collisions.iter_mut() {
+        collision.guards.sort();
+        collision.guards.dedup();
+    }
+    collisions.sort_by_key(|collision| (collision.tick, collision.position, collision.crossed));
+    collisions
+}
+
+#[test]
+fn pairwise_collisions_match_replaying_every_tick() {
+    let day = aoc::find_day(6).expect("Day is not registered");
+    let knobs = [("size".to_string(), 10), ("obstacles".to_string(), 30)];
+    let mut rng = Rng::new(6);
+    for seed in 0..100 {
+        let input = day.generate(seed, &knobs).expect("Knobs are valid");
+        let mut map = Day6::parse(&input).expect("Generated input is valid").0;
+        for _ in 0..4 {
+            let free: Vec<Pos> = map.find_all(&'.').collect();
+            if let Some(&pos) = free.get(rng.below(free.len().max(1) as u64) as usize) {
+                map[pos] = *rng.pick(&['^', '>', 'v', '<']);
+            }
+        }
+
+        let guards = find_guards(&map);
+        let report = patrol(&map, &guards, TurnPolicy::Right);
+        let ticks = match report.cycle {
+            Some(Cycle::Ticks(length)) => report.ticks + length as usize,
+            _ => report.ticks,
+        };
+        assert_eq!(
+            report.collisions,
+            replayed_collisions(&map, &guards, ticks),
+            "seed {}",
+            seed
+        );
+    }
+}
@@ -0,0 +1,90 @@
+use common::{Dir, Pos, Solution};
+use day6::{find_obstructions, loop_cycle, render_patrol, Day6, PatrolLoop};
+
+#[test]
+fn example_obstructions_are_found() {
+    let (map, direction, position) = Day6::parse(day6::EXAMPLES[0]).expect("Example is valid");
+    let expected =
+        [(6, 3), (7, 6), (7, 7), (8, 1), (8, 3), (9, 7)].map(|(row, col)| Pos::new(row, col));
+    for threads in [1, 2, 4, 100] {
+        assert_eq!(
+            find_obstructions(&map, direction, position, threads).expect("Guard leaves"),
+            expected
+        );
+    }
+}
+
+#[test]
+fn thread_count_doesnt_change_the_result() {
+    let day = aoc::find_day(6).expect("Day is not registered");
+    for seed in 0..4 {
+        let knobs = [("size".to_string(), 40), ("obstacles".to_string(), 10)];
+        let input = day.generate(seed, &knobs).expect("Knobs are valid");
+        let (map, direction, position) = Day6::parse(&input).expect("Generated input is valid");
+
+        let serial = find_obstructions(&map, direction, position, 1).expect("Guard leaves");
+        assert_eq!(
+            find_obstructions(&map, direction, position, 3),
+            Ok(serial.clone())
+        );
+        assert_eq!(
+            serial.len().to_string(),
+            Day6::part2(&(map, direction, position)).to_string()
+        );
+    }
+}
+
+#[test]
+fn patrol_is_drawn_with_obstructions() {
+    let (map, direction, position) = Day6::parse(day6::EXAMPLES[0]).expect("Example is valid");
+    let obstructions = find_obstructions(&map, direction, position, 1).expect("Guard leaves");
+    assert_eq!(
+        render_patrol(&map, direction, position, &obstructions),
+        "....#.....\n\
+         ....+---+#\n\
+         ....|...|.\n\
+         ..#.|...|.\n\
+         ..+-+-+#|.\n\
+         ..|.|.|.|.\n\
+         .#+O^-+-+.\n\
+         .+----OO#.\n\
+         #O-O--+|..\n\
+         ......#O..\n"
+    );
+
+    // Up from the start, then around the 1,4 to 6,8 rectangle back to it
+    let cycle = loop_cycle(&map, direction, position, Pos::new(6, 3)).expect("Guard is trapped");
+    assert_eq!(cycle.len(), 22);
+    assert_eq!(cycle[0], (Pos::new(6, 4), Dir::Up));
+    let turns: Vec<(Pos, Dir)> = cycle
+        .iter()
+        .zip(cycle.iter().cycle().skip(1))
+        .filter(|(state, next)| state.0 == next.0)
+        .map(|(_, &next)| next)
+        .collect();
+    assert_eq!(
+        turns,
+        [
+            (Pos::new(1, 4), Dir::Right),
+            (Pos::new(1, 8), Dir::Down),
+            (Pos::new(6, 8), Dir::Left),
+            (Pos::new(6, 4), Dir::Up),
+        ]
+    );
+    assert_eq!(loop_cycle(&map, direction, position, Pos::new(0, 0)), None);
+}
+
+#[test]
+fn patrol_that_already_loops_is_an_error() {
+    let lab = Day6::parse(".#...\n....#\n.^...\n#....\n...#.\n").expect("Lab is valid");
+    let (map, direction, position) = &lab;
+    let err = find_obstructions(map, *direction, *position, 2).expect_err("Guard never leaves");
+    assert_eq!(
+        err,
+        PatrolLoop {
+            position: Pos::new(2, 1),
+            direction: Dir::Up,
+        }
+    );
+    assert_eq!(Day6::part1(&lab).to_string(), err.to_string());
+}
@@ -0,0 +1,45 @@
+use common::Solution;
+use day9::Day9;
+
+// Moves whole files block by block, scanning the disk from the left for every file
+fn compact_blocks(disk_map: &[usize]) -> i64 {
+    let mut blocks: Vec<Option<usize>> = vec![];
+    for (i, &size) in disk_map.iter().enumerate() {
+        let id = (i % 2 == 0).then_some(i / 2);
+        blocks.extend(std::iter::repeat_n(id, size));
+    }
+
+    for id in (0..disk_map.len().div_ceil(2)).rev() {
+        let size = disk_map[id * 2];
+        let Some(start) = blocks.iter().position(|&block| block == Some(id)) else {
+            continue;
+        };
+        let free = (0..start).find(|&span| blocks[span..span + size].iter().all(Option::is_none));
+        if let Some(span) = free {
+            blocks[start..start + size].fill(None);
+            blocks[span..span + size].fill(Some(id));
+        }
+    }
+
+    blocks
+        .iter()
+        .enumerate()
+        .filter_map(|(position, block)| block.map(|id| (position * id) as i64))
+        .sum()
+}
+
+#[test]
+fn heap_compaction_matches_block_moves() {
+    let day = aoc::find_day(9).expect("Day is not registered");
+    for seed in 0..10 {
+        let knobs = [("files".to_string(), 300)];
+        let input = day.generate(seed, &knobs).expect("Knobs are valid");
+        let disk_map = Day9::parse(&input).expect("Generated input is valid");
+        assert_eq!(
+            Day9::part2(&disk_map).to_string(),
+            compact_blocks(&disk_map).to_string(),
+            "seed {}",
+            seed
+        );
+    }
+}
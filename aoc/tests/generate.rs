@@ -0,0 +1,173 @@
+use common::Answer;
+
+fn generate(number: u8, seed: u64, knobs: &[(&str, usize)]) -> String {
+    let day = aoc::find_day(number).expect("Day is not registered");
+    let knobs: Vec<(String, usize)> = knobs
+        .iter()
+        .map(|&(name, value)| (name.to_string(), value))
+        .collect();
+    day.generate(seed, &knobs)
+        .unwrap_or_else(|err| panic!("{}", err))
+}
+
+// Knobs whose upper end takes seconds to solve in a debug build, that input is only parsed
+const SLOW_TO_SOLVE: &[(u8, &str)] = &[
+    (5, "updates"),
+    (7, "equations"),
+    (7, "max_numbers"),
+    (9, "files"),
+    (11, "stones"),
+    (12, "size"),
+    (13, "machines"),
+    (14, "robots"),
+    (16, "size"),
+    (19, "towels"),
+    (19, "designs"),
+    (21, "codes"),
+    (22, "buyers"),
+    (23, "degree"),
+    (23, "party"),
+    (25, "locks"),
+    (25, "keys"),
+];
+
+#[test]
+fn every_day_generates_parseable_deterministic_input() {
+    for day in &aoc::DAYS {
+        let input = generate(day.number, 7, &[]);
+        assert_eq!(input, generate(day.number, 7, &[]), "day {}", day.number);
+        assert_ne!(input, generate(day.number, 8, &[]), "day {}", day.number);
+
+        if let Err(err) = day.solve(&input, Some(1)) {
+            panic!("{}", err.render(&input));
+        }
+
+        // Every knob value in range has to give a valid input, the others stay at their defaults.
+        // Part 1 runs on it too, part 2 is left to the per-day tests as it's often much slower.
+        for knob in day.knobs {
+            for value in [*knob.range.start(), *knob.range.end()] {
+                let input = generate(day.number, 7, &[(knob.name, value)]);
+                if SLOW_TO_SOLVE.contains(&(day.number, knob.name)) && value > knob.default {
+                    if let Err(err) = day.parse(&input) {
+                        panic!("{}={}: {}", knob.name, value, err.render(&input));
+                    }
+                    continue;
+                }
+                let answers = day.solve(&input, Some(1)).unwrap_or_else(|err| {
+                    panic!("{}={}: {}", knob.name, value, err.render(&input))
+                });
+                if let Answer::Failed(reason) = &answers[0].answer {
+                    panic!("day {} {}={}: {}", day.number, knob.name, value, reason);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn generated_inputs_solve_on_small_sizes() {
+    let cases: [(u8, &[(&str, usize)]); 6] = [
+        (1, &[("lines", 50)]),
+        (5, &[("pages", 11), ("updates", 20), ("max_update", 7)]),
+        (17, &[("a_bits", 12)]),
+        (23, &[("computers", 40), ("degree", 4), ("party", 6)]),
+        (24, &[("bits", 12), ("swaps", 2)]),
+        (25, &[("locks", 10), ("keys", 10)]),
+    ];
+
+    for (number, knobs) in cases {
+        let day = aoc::find_day(number).expect("Day is not registered");
+        for seed in 0..5 {
+            let input = generate(number, seed, knobs);
+            let answers = day
+                .solve(&input, None)
+                .unwrap_or_else(|err| panic!("{}", err.render(&input)));
+            assert_eq!(answers.len(), 2, "day {} seed {}", number, seed);
+        }
+    }
+}
+
+// Sum of the x and y inputs of a generated circuit
+fn expected_sum(input: &str) -> i128 {
+    input
+        .lines()
+        .take_while(|line| !line.is_empty())
+        .map(|line| {
+            let (wire, value) = line.split_once(": ").expect("Wire line");
+            let bit: u32 = wire[1..].parse().expect("Bit number");
+            value.parse::<i128>().expect("Bit value") << bit
+        })
+        .sum()
+}
+
+#[test]
+fn generated_adders_add_until_swapped() {
+    let day = aoc::find_day(24).expect("Day is not registered");
+
+    for seed in 0..5 {
+        let input = generate(24, seed, &[("swaps", 0)]);
+        let answers = day
+            .solve(&input, Some(1))
+            .expect("Generated input is valid");
+        assert_eq!(answers[0].answer, Answer::from(expected_sum(&input)));
+
+        let input = generate(24, seed, &[("bits", 99), ("swaps", 0)]);
+        let answers = day
+            .solve(&input, Some(1))
+            .expect("Generated input is valid");
+        assert_eq!(answers[0].answer, Answer::from(expected_sum(&input)));
+
+        for (bits, swaps) in [(20, 4), (9, 4), (45, 22)] {
+            let input = generate(24, seed, &[("bits", bits), ("swaps", swaps)]);
+            let answers = day
+                .solve(&input, Some(2))
+                .expect("Generated input is valid");
+            let wires = answers[0].answer.to_string();
+            assert_eq!(
+                wires.split(',').count(),
+                2 * swaps,
+                "seed {}: {}",
+                seed,
+                wires
+            );
+        }
+    }
+}
+
+#[test]
+fn unknown_and_out_of_range_knobs_are_rejected() {
+    let day = aoc::find_day(5).expect("Day is not registered");
+
+    let err = day.generate(0, &[("rules".to_string(), 3)]).unwrap_err();
+    assert_eq!(
+        err,
+        "Day 5: Unknown knob rules (available: pages, updates, max_update)"
+    );
+
+    let err = day.generate(0, &[("pages".to_string(), 1)]).unwrap_err();
+    assert_eq!(err, "Day 5: Knob pages must be between 2 and 90, got 1");
+
+    // Each knob is in range, but 9 bits only leave room for 4 swaps
+    let day = aoc::find_day(24).expect("Day is not registered");
+    let knobs = [("bits".to_string(), 9), ("swaps".to_string(), 5)];
+    let err = day.generate(0, &knobs).unwrap_err();
+    assert!(err.starts_with("Day 24: Knob swaps can be at most 4 with 9 bits"));
+}
+
+#[test]
+fn bytes_that_never_cut_off_the_exit_fail_day18_part2() {
+    let day = aoc::find_day(18).expect("Day is not registered");
+    let input = generate(18, 3, &[("bytes", 1)]);
+    let answers = day
+        .solve(&input, Some(2))
+        .expect("Generated input is valid");
+    assert_eq!(
+        answers[0].answer,
+        Answer::Failed("The exit is still reachable after every byte has fallen".to_string())
+    );
+
+    let answers = day
+        .solve(&generate(18, 3, &[]), Some(2))
+        .expect("Generated input is valid");
+    assert!(matches!(answers[0].answer, Answer::Text(_)));
+}
@@ -0,0 +1,69 @@
+use common::ParseError;
+
+fn parse_error(number: u8, input: &str) -> ParseError {
+    let day = aoc::find_day(number).expect("Day is not registered");
+    match day.solve(input, Some(1)) {
+        Ok(_) => panic!("day {} accepted malformed input:\n{}", number, input),
+        Err(err) => err,
+    }
+}
+
+#[test]
+fn parse_errors_point_at_the_offending_token() {
+    let cases: [(u8, &str, usize, usize, &str); 8] = [
+        (1, "3   4\n4   x3\n", 2, 5, "location ID"),
+        (6, "..#\n..\n", 2, 3, "row of width 3"),
+        (
+            13,
+            "Button A: X+94, Y+34\nButton B: X+22, Y=67\n",
+            2,
+            17,
+            "'Y+'",
+        ),
+        (
+            17,
+            "Register A: 7\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,9,4\n",
+            5,
+            14,
+            "3-bit number",
+        ),
+        (18, "5,4\n4,71\n", 2, 3, "coordinate below 71"),
+        (21, "029A\n98x\n", 2, 3, "digit or final 'A'"),
+        (21, "029A\n1234A\n", 2, 4, "final 'A'"),
+        (24, "x00: 1\nx01: 2\n", 2, 6, "0 or 1"),
+    ];
+
+    for (number, input, line, column, expected) in cases {
+        let err = parse_error(number, input);
+        assert_eq!(
+            (err.day, err.line, err.column, err.expected.as_str()),
+            (Some(number), line, column, expected),
+            "day {}",
+            number
+        );
+    }
+}
+
+#[test]
+fn parse_error_renders_caret_under_column() {
+    let input = "3   4\n4   x3\n";
+    let err = parse_error(1, input);
+
+    assert_eq!(
+        err.render(input),
+        "error: day 1, line 2, column 5: expected location ID\n  |\n2 | 4   x3\n  |     ^"
+    );
+}
+
+#[test]
+fn missing_sections_are_reported() {
+    assert_eq!(
+        parse_error(6, "...\n.#.\n").expected,
+        "a guard (^, v, < or >)"
+    );
+    assert_eq!(parse_error(16, "#S#\n#.#\n").expected, "an end tile E");
+    assert_eq!(parse_error(15, "#.O#\n\n<<\n").expected, "a robot @");
+    assert_eq!(parse_error(4, "").expected, "grid");
+    assert_eq!(parse_error(9, "\n").expected, "digit");
+    assert_eq!(parse_error(18, "").expected, "a falling byte");
+}
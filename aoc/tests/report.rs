@@ -0,0 +1,57 @@
+use aoc::report;
+use common::Answer;
+
+#[test]
+fn json_report_has_one_record_per_part() {
+    let day = aoc::find_day(18).expect("Day is not registered");
+    let answers = day.solve(day.examples[0], None).expect("Example is valid");
+
+    let json = report::to_json(&answers);
+    assert_eq!(json.lines().count(), 4);
+    assert!(json.contains("{\"day\": 18, \"part\": 1, \"answer\": 22, \"elapsed_ns\": "));
+    assert!(json.contains("{\"day\": 18, \"part\": 2, \"answer\": \"6,1\", \"elapsed_ns\": "));
+
+    let text = report::to_text(&answers);
+    assert_eq!(text, "Day 18 part 1: 22\nDay 18 part 2: 6,1\n");
+}
+
+#[test]
+fn failed_parts_keep_their_reason() {
+    let day = aoc::find_day(24).expect("Day is not registered");
+    let input = "x00: 1\ny00: 0\n\nx00 AND qqq -> z00\n";
+    let answers = day.solve(input, Some(1)).expect("Input is valid");
+
+    let reason = "Gate z00 reads wire qqq, which is neither an input nor driven by a gate";
+    assert_eq!(answers[0].answer, Answer::Failed(reason.to_string()));
+    assert!(report::to_json(&answers).contains(&format!(
+        "\"answer\": null, \"error\": \"{}\", \"elapsed_ns\": ",
+        reason
+    )));
+}
+
+#[test]
+fn unreachable_ends_fail_instead_of_answering() {
+    let cases: [(u8, &str, &str); 2] = [
+        (16, "#####\n#S#E#\n#####\n", "No path from S to E"),
+        (
+            18,
+            "0,1\n1,0\n",
+            "No path from 0,0 to the exit once the first 2 bytes fell",
+        ),
+    ];
+
+    for (number, input, reason) in cases {
+        let day = aoc::find_day(number).expect("Day is not registered");
+        let answers = day.solve(input, Some(1)).expect("Input is valid");
+        assert_eq!(answers[0].answer, Answer::Failed(reason.to_string()));
+    }
+
+    let day = aoc::find_day(16).expect("Day is not registered");
+    let answers = day
+        .solve("#####\n#S#E#\n#####\n", Some(2))
+        .expect("Input is valid");
+    assert_eq!(
+        answers[0].answer,
+        Answer::Failed("No path from S to E".to_string())
+    );
+}
@@ -0,0 +1,57 @@
+use std::fmt;
+
+// Puzzle answers are either numbers or free-form text (Day17's output list, Day23's password)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i128),
+    Text(String),
+    None,
+    // The part couldn't be solved for this input, with the reason why
+    Failed(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+            Answer::None => write!(f, "-"),
+            Answer::Failed(reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(value: $ty) -> Self {
+                    Answer::Int(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl<T: Into<Answer>, E: fmt::Display> From<Result<T, E>> for Answer {
+    fn from(result: Result<T, E>) -> Self {
+        match result {
+            Ok(value) => value.into(),
+            Err(err) => Answer::Failed(err.to_string()),
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+use std::ops::{Range, RangeInclusive};
+
+use crate::{Dir, Grid, Pos};
+
+// SplitMix64, tiny and platform independent so a seed always produces the same input
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    // Uniform value in 0..bound
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "Empty range");
+        ((self.next_u64() as u128 * bound as u128) >> 64) as u64
+    }
+
+    pub fn range(&mut self, range: Range<usize>) -> usize {
+        range.start + self.below((range.end - range.start) as u64) as usize
+    }
+
+    // True with probability `percent` / 100
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.below(100) < percent
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0..i + 1));
+        }
+    }
+}
+
+// A size parameter of a day's generator, e.g. the number of lines
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Knob {
+    pub name: &'static str,
+    pub default: usize,
+    // Values the generator still produces a valid input for with the other knobs at their
+    // defaults, `Generator::check` rejects combinations that don't fit together
+    pub range: RangeInclusive<usize>,
+    pub help: &'static str,
+}
+
+// Knob values for a single generator run, defaults overridden by the user
+#[derive(Debug, Clone)]
+pub struct Knobs {
+    values: HashMap<&'static str, usize>,
+}
+
+impl Knobs {
+    pub fn new(knobs: &'static [Knob], overrides: &[(String, usize)]) -> Result<Self, String> {
+        let mut values: HashMap<&'static str, usize> =
+            knobs.iter().map(|knob| (knob.name, knob.default)).collect();
+
+        for (name, value) in overrides {
+            let knob = knobs.iter().find(|knob| knob.name == name).ok_or_else(|| {
+                let names: Vec<&str> = knobs.iter().map(|knob| knob.name).collect();
+                format!("Unknown knob {} (available: {})", name, names.join(", "))
+            })?;
+            if !knob.range.contains(value) {
+                return Err(format!(
+                    "Knob {} must be between {} and {}, got {}",
+                    name,
+                    knob.range.start(),
+                    knob.range.end(),
+                    value
+                ));
+            }
+            values.insert(knob.name, *value);
+        }
+
+        Ok(Knobs { values })
+    }
+
+    pub fn get(&self, name: &str) -> usize {
+        *self
+            .values
+            .get(name)
+            .unwrap_or_else(|| panic!("Generator reads undeclared knob {}", name))
+    }
+}
+
+// Produces random inputs in the puzzle's format, used for stress and property testing
+pub trait Generator {
+    const KNOBS: &'static [Knob];
+
+    // Called before `generate`, for limits of one knob that depend on another
+    fn check(_knobs: &Knobs) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String;
+}
+
+// Square maze of '#' walls and '.' floor with exactly one path between any two floor tiles.
+// Floor tiles sit at odd rows and columns, `size` is rounded down to an odd number.
+pub fn generate_maze(rng: &mut Rng, size: usize) -> Grid<char> {
+    let size = (size.max(3) - 1) / 2 * 2 + 1;
+    let mut maze = Grid::new(size, size, '#');
+
+    // Randomised depth-first search from the bottom-left corner
+    let start = Pos::new(size - 2, 1);
+    let mut stack = vec![start];
+    maze[start] = '.';
+    while let Some(&pos) = stack.last() {
+        let unvisited: Vec<(Pos, Pos)> = Dir::ORTHOGONAL
+            .into_iter()
+            .filter_map(|dir| {
+                let wall = maze.step(pos, dir)?;
+                let next = maze.step(wall, dir)?;
+                (next.row % 2 == 1 && next.col % 2 == 1 && maze[next] == '#')
+                    .then_some((wall, next))
+            })
+            .collect();
+
+        if unvisited.is_empty() {
+            stack.pop();
+        } else {
+            let (wall, next) = *rng.pick(&unvisited);
+            maze[wall] = '.';
+            maze[next] = '.';
+            stack.push(next);
+        }
+    }
+
+    maze
+}
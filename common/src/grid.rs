@@ -0,0 +1,271 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::{parse_grid, ParseError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Pos {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Pos {
+    pub const fn new(row: usize, col: usize) -> Self {
+        Pos { row, col }
+    }
+
+    // None when the step would leave the first quadrant, the grid bounds are checked by `Grid::step`
+    pub fn step(self, dir: Dir) -> Option<Pos> {
+        self.offset(dir.delta())
+    }
+
+    pub fn offset(self, (d_row, d_col): (isize, isize)) -> Option<Pos> {
+        Some(Pos {
+            row: self.row.checked_add_signed(d_row)?,
+            col: self.col.checked_add_signed(d_col)?,
+        })
+    }
+
+    pub fn manhattan(self, other: Pos) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+// Listed clockwise starting from Up
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir {
+    pub const ORTHOGONAL: [Dir; 4] = [Dir::Up, Dir::Right, Dir::Down, Dir::Left];
+    pub const ALL: [Dir; 8] = [
+        Dir::Up,
+        Dir::UpRight,
+        Dir::Right,
+        Dir::DownRight,
+        Dir::Down,
+        Dir::DownLeft,
+        Dir::Left,
+        Dir::UpLeft,
+    ];
+
+    // (row, column) offset of a single step
+    pub const fn delta(self) -> (isize, isize) {
+        match self {
+            Dir::Up => (-1, 0),
+            Dir::UpRight => (-1, 1),
+            Dir::Right => (0, 1),
+            Dir::DownRight => (1, 1),
+            Dir::Down => (1, 0),
+            Dir::DownLeft => (1, -1),
+            Dir::Left => (0, -1),
+            Dir::UpLeft => (-1, -1),
+        }
+    }
+
+    fn rotate(self, eighths: usize) -> Dir {
+        Dir::ALL[(self as usize + eighths) % 8]
+    }
+
+    pub fn turn_right(self) -> Dir {
+        self.rotate(2)
+    }
+
+    pub fn turn_left(self) -> Dir {
+        self.rotate(6)
+    }
+
+    pub fn reverse(self) -> Dir {
+        self.rotate(4)
+    }
+
+    pub fn from_arrow(c: char) -> Option<Dir> {
+        match c {
+            '^' => Some(Dir::Up),
+            '>' => Some(Dir::Right),
+            'v' => Some(Dir::Down),
+            '<' => Some(Dir::Left),
+            _ => None,
+        }
+    }
+
+    pub fn to_arrow(self) -> Option<char> {
+        match self {
+            Dir::Up => Some('^'),
+            Dir::Right => Some('>'),
+            Dir::Down => Some('v'),
+            Dir::Left => Some('<'),
+            _ => None,
+        }
+    }
+}
+
+// Row-major grid stored in a single Vec
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    // Rows have to be of equal length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "Grid rows differ in length"
+        );
+
+        Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        parse_grid(input, expected, cell).map(Grid::from_rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.row < self.height && pos.col < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.row * self.width + pos.col])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.row * self.width + pos.col])
+        } else {
+            None
+        }
+    }
+
+    // Neighbouring position in `dir`, None when it falls outside the grid
+    pub fn step(&self, pos: Pos, dir: Dir) -> Option<Pos> {
+        pos.step(dir).filter(|&next| self.contains(next))
+    }
+
+    pub fn neighbours(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Dir::ORTHOGONAL
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Dir::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Pos::new(row, col)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn find(&self, value: &T) -> Option<Pos>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .find(|(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Pos> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(col).step_by(self.width.max(1))
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        assert!(self.contains(pos), "{:?} is outside the grid", pos);
+        &self.cells[pos.row * self.width + pos.col]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        assert!(self.contains(pos), "{:?} is outside the grid", pos);
+        &mut self.cells[pos.row * self.width + pos.col]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
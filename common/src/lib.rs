@@ -0,0 +1,19 @@
+mod answer;
+mod generate;
+mod grid;
+mod parse;
+pub mod search;
+
+pub use answer::Answer;
+pub use generate::{generate_maze, Generator, Knob, Knobs, Rng};
+pub use grid::{Dir, Grid, Pos};
+
+pub use parse::{parse_grid, parse_token, ParseError};
+
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+}
@@ -0,0 +1,121 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+// Line and column are 1-based, the column counts characters rather than bytes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: Option<u8>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: impl Into<String>) -> Self {
+        ParseError {
+            day: None,
+            line,
+            column,
+            expected: expected.into(),
+        }
+    }
+
+    // `token` has to be a slice of `input`, its position is recovered from the pointer offset.
+    // An empty slice (e.g. `&line[line.len()..]`) points just past the end of a line.
+    pub fn at(input: &str, token: &str, expected: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .saturating_sub(input.as_ptr() as usize)
+            .min(input.len());
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+
+        ParseError::new(
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+            expected,
+        )
+    }
+
+    pub fn with_day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+
+    // Compiler style diagnostic with the offending line and a caret under the column
+    pub fn render(&self, input: &str) -> String {
+        let source_line = input
+            .lines()
+            .nth(self.line.saturating_sub(1))
+            .unwrap_or_default();
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let caret = " ".repeat(self.column.saturating_sub(1));
+
+        format!(
+            "error: {}\n{} |\n{} | {}\n{} | {}^",
+            self, gutter, number, source_line, gutter, caret
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {}, ", day)?;
+        }
+        write!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+pub fn parse_token<T: FromStr>(input: &str, token: &str, expected: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(input, token, expected))
+}
+
+// Rectangular grid of cells, `cell` rejects characters by returning None
+pub fn parse_grid<T>(
+    input: &str,
+    expected: &str,
+    cell: impl Fn(char) -> Option<T>,
+) -> Result<Vec<Vec<T>>, ParseError> {
+    let mut grid: Vec<Vec<T>> = vec![];
+
+    for line in input.lines() {
+        let row = line
+            .char_indices()
+            .map(|(idx, c)| {
+                cell(c)
+                    .ok_or_else(|| ParseError::at(input, &line[idx..idx + c.len_utf8()], expected))
+            })
+            .collect::<Result<Vec<T>, _>>()?;
+
+        if let Some(width) = grid.first().map(Vec::len) {
+            if row.len() != width {
+                let end = line
+                    .char_indices()
+                    .nth(width)
+                    .map_or(line.len(), |(idx, _)| idx);
+                return Err(ParseError::at(
+                    input,
+                    &line[end..],
+                    format!("row of width {}", width),
+                ));
+            }
+        }
+        grid.push(row);
+    }
+
+    if grid.first().is_none_or(Vec::is_empty) {
+        return Err(ParseError::new(1, 1, "grid"));
+    }
+
+    Ok(grid)
+}
@@ -0,0 +1,184 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+// Number of steps to every node reachable from `start`
+pub fn bfs<N, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(current) = queue.pop_front() {
+        let next_distance = distances[&current] + 1;
+        for next in neighbours(&current) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), next_distance);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    distances
+}
+
+// Result of a full Dijkstra run: the cost of every reachable node and, for each node, every
+// predecessor it can be reached from at that cost
+pub struct ShortestPaths<N, C> {
+    start: N,
+    distances: HashMap<N, C>,
+    predecessors: HashMap<N, Vec<N>>,
+}
+
+impl<N, C> ShortestPaths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy,
+{
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<N, C> {
+        &self.distances
+    }
+
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.predecessors.get(node).map_or(&[], Vec::as_slice)
+    }
+
+    // One shortest path from the start to `end`, both included
+    pub fn path_to(&self, end: &N) -> Option<Vec<N>> {
+        self.distances.get(end)?;
+
+        let mut path = vec![end.clone()];
+        while *path.last()? != self.start {
+            let previous = self.predecessors(path.last()?).first()?;
+            path.push(previous.clone());
+        }
+
+        path.reverse();
+        Some(path)
+    }
+
+    // Every shortest path from the start to `end`, grows exponentially on open grids
+    pub fn paths_to(&self, end: &N) -> Vec<Vec<N>> {
+        if !self.distances.contains_key(end) {
+            return vec![];
+        }
+        if *end == self.start {
+            return vec![vec![end.clone()]];
+        }
+
+        self.predecessors(end)
+            .iter()
+            .flat_map(|previous| self.paths_to(previous))
+            .map(|mut path| {
+                path.push(end.clone());
+                path
+            })
+            .collect()
+    }
+
+    // Nodes lying on at least one shortest path to any of `ends`
+    pub fn nodes_on_paths_to(&self, ends: impl IntoIterator<Item = N>) -> HashSet<N> {
+        let mut nodes = HashSet::new();
+        let mut stack: Vec<N> = ends
+            .into_iter()
+            .filter(|end| self.distances.contains_key(end))
+            .collect();
+
+        while let Some(node) = stack.pop() {
+            if nodes.insert(node.clone()) {
+                stack.extend(self.predecessors(&node).iter().cloned());
+            }
+        }
+
+        nodes
+    }
+}
+
+pub fn dijkstra<N, C, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> ShortestPaths<N, C>
+where
+    N: Clone + Eq + Hash + Ord,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut distances = HashMap::from([(start.clone(), C::default())]);
+    let mut predecessors: HashMap<N, Vec<N>> = HashMap::new();
+    let mut heap = BinaryHeap::from([Reverse((C::default(), start.clone()))]);
+
+    while let Some(Reverse((current_distance, current))) = heap.pop() {
+        if distances[&current] < current_distance {
+            continue;
+        }
+
+        for (next, cost) in neighbours(&current) {
+            let next_distance = current_distance + cost;
+
+            match distances.get(&next) {
+                Some(&known) if next_distance > known => {}
+                Some(&known) if next_distance == known => {
+                    predecessors.entry(next).or_default().push(current.clone());
+                }
+                _ => {
+                    distances.insert(next.clone(), next_distance);
+                    predecessors.insert(next.clone(), vec![current.clone()]);
+                    heap.push(Reverse((next_distance, next)));
+                }
+            }
+        }
+    }
+
+    ShortestPaths {
+        start,
+        distances,
+        predecessors,
+    }
+}
+
+// Cheapest path to the first goal reached, `heuristic` must never overestimate the remaining cost
+pub fn astar<N, C, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(C, Vec<N>)>
+where
+    N: Clone + Eq + Hash + Ord,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut costs = HashMap::from([(start.clone(), C::default())]);
+    let mut parents: HashMap<N, N> = HashMap::new();
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), C::default(), start))]);
+
+    while let Some(Reverse((_, cost, current))) = heap.pop() {
+        if costs[&current] < cost {
+            continue;
+        }
+
+        if is_goal(&current) {
+            let mut path = vec![current];
+            while let Some(parent) = parents.get(path.last()?) {
+                path.push(parent.clone());
+            }
+            path.reverse();
+            return Some((cost, path));
+        }
+
+        for (next, step_cost) in neighbours(&current) {
+            let next_cost = cost + step_cost;
+            if costs.get(&next).is_none_or(|&known| next_cost < known) {
+                costs.insert(next.clone(), next_cost);
+                parents.insert(next.clone(), current.clone());
+                heap.push(Reverse((next_cost + heuristic(&next), next_cost, next)));
+            }
+        }
+    }
+
+    None
+}
@@ -0,0 +1,53 @@
+use common::{generate_maze, search, Knob, Knobs, Pos, Rng};
+
+#[test]
+fn rng_is_deterministic_and_stays_in_range() {
+    let mut a = Rng::new(42);
+    let mut b = Rng::new(42);
+    for _ in 0..1000 {
+        let value = a.range(3..10);
+        assert_eq!(value, b.range(3..10));
+        assert!((3..10).contains(&value));
+    }
+
+    let mut items: Vec<u32> = (0..50).collect();
+    a.shuffle(&mut items);
+    assert_ne!(items, (0..50).collect::<Vec<_>>());
+    items.sort();
+    assert_eq!(items, (0..50).collect::<Vec<_>>());
+}
+
+#[test]
+fn knobs_fall_back_to_defaults() {
+    const KNOBS: &[Knob] = &[
+        Knob {
+            name: "width",
+            default: 10,
+            range: 1..=100,
+            help: "",
+        },
+        Knob {
+            name: "height",
+            default: 5,
+            range: 1..=100,
+            help: "",
+        },
+    ];
+
+    let knobs = Knobs::new(KNOBS, &[("height".to_string(), 7)]).expect("Known knob");
+    assert_eq!((knobs.get("width"), knobs.get("height")), (10, 7));
+    assert!(Knobs::new(KNOBS, &[("depth".to_string(), 1)]).is_err());
+    assert!(Knobs::new(KNOBS, &[("width".to_string(), 0)]).is_err());
+}
+
+#[test]
+fn maze_connects_every_floor_tile() {
+    let maze = generate_maze(&mut Rng::new(3), 22);
+    assert_eq!((maze.width(), maze.height()), (21, 21));
+
+    let start = Pos::new(19, 1);
+    let reachable = search::bfs(start, |&pos| {
+        maze.neighbours(pos).filter(|&next| maze[next] == '.')
+    });
+    assert_eq!(reachable.len(), maze.find_all(&'.').count());
+}
@@ -0,0 +1,38 @@
+use common::{Dir, Grid, Pos};
+
+fn sample() -> Grid<char> {
+    Grid::parse("ab.\n.#c\n", "cell", Some).expect("Sample grid is valid")
+}
+
+#[test]
+fn parse_keeps_row_major_layout() {
+    let grid = sample();
+
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[Pos::new(1, 2)], 'c');
+    assert_eq!(grid.row(1), ['.', '#', 'c']);
+    assert_eq!(grid.column(1).collect::<String>(), "b#");
+    assert_eq!(grid.find(&'#'), Some(Pos::new(1, 1)));
+    assert_eq!(grid.find_all(&'.').count(), 2);
+    assert_eq!(grid.to_string(), "ab.\n.#c\n");
+}
+
+#[test]
+fn neighbours_stay_inside_the_grid() {
+    let grid = sample();
+
+    let corner: Vec<Pos> = grid.neighbours(Pos::new(0, 0)).collect();
+    assert_eq!(corner, [Pos::new(0, 1), Pos::new(1, 0)]);
+    assert_eq!(grid.neighbours8(Pos::new(0, 0)).count(), 3);
+    assert_eq!(grid.neighbours8(Pos::new(1, 1)).count(), 5);
+    assert_eq!(grid.step(Pos::new(1, 2), Dir::Right), None);
+}
+
+#[test]
+fn directions_turn_clockwise() {
+    assert_eq!(Dir::Up.turn_right(), Dir::Right);
+    assert_eq!(Dir::Left.turn_right(), Dir::Up);
+    assert_eq!(Dir::Up.turn_left(), Dir::Left);
+    assert_eq!(Dir::DownRight.reverse(), Dir::UpLeft);
+    assert_eq!(Dir::from_arrow('v'), Some(Dir::Down));
+}
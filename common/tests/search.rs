@@ -0,0 +1,44 @@
+use common::search::{self, ShortestPaths};
+
+// Two equally cheap routes from 0 to 3 and a dearer direct edge
+fn diamond(node: &u32) -> Vec<(u32, u32)> {
+    match node {
+        0 => vec![(1, 1), (2, 2), (3, 5)],
+        1 => vec![(3, 3)],
+        2 => vec![(3, 2)],
+        _ => vec![],
+    }
+}
+
+#[test]
+fn bfs_counts_steps_to_reachable_nodes() {
+    let distances = search::bfs(0u32, |&n| [n + 1, n * 2].into_iter().filter(|&m| m <= 6));
+
+    assert_eq!(distances[&0], 0);
+    assert_eq!(distances[&4], 3);
+    assert_eq!(distances[&6], 4);
+    assert_eq!(distances.len(), 7);
+}
+
+#[test]
+fn dijkstra_keeps_every_shortest_path() {
+    let paths: ShortestPaths<u32, u32> = search::dijkstra(0, diamond);
+
+    assert_eq!(paths.distance(&3), Some(4));
+    assert_eq!(paths.distance(&4), None);
+    assert_eq!(paths.path_to(&0), Some(vec![0]));
+
+    let mut all = paths.paths_to(&3);
+    all.sort();
+    assert_eq!(all, [vec![0, 1, 3], vec![0, 2, 3]]);
+    assert_eq!(paths.nodes_on_paths_to([3]).len(), 4);
+}
+
+#[test]
+fn astar_stops_at_the_goal() {
+    let (cost, path) = search::astar(0, diamond, |_| 0, |&n| n == 3).expect("Goal is reachable");
+
+    assert_eq!(cost, 4);
+    assert_eq!(path.len(), 3);
+    assert!(search::astar(1, diamond, |_| 0, |&n| n == 0).is_none());
+}